@@ -1,8 +1,10 @@
 extern crate test;
 
 #[allow(soft_unstable)]
+#[allow(clippy::module_inception)]
 #[cfg(test)]
 mod bench {
+    use crate::tests::common::random_symmetric;
     use crate::*;
 
     use ndarray::Array;
@@ -68,6 +70,46 @@ mod bench {
         })
     }
 
+    #[bench]
+    fn bench_divide_and_conquer(b: &mut Bencher) {
+        let opts = QROptions {
+            algorithm: QRAlgorithm::DivideAndConquer,
+            ..DEFAULT_OPTS
+        };
+
+        b.iter(|| {
+            let a = random_symmetric(BENCH_SIZE);
+
+            let (t, _) = schur_form_opts(a.view(), &opts).unwrap();
+            black_box(&t);
+        })
+    }
+
+    #[bench]
+    fn bench_mrrr(b: &mut Bencher) {
+        let opts = QROptions {
+            algorithm: QRAlgorithm::Mrrr,
+            ..DEFAULT_OPTS
+        };
+
+        b.iter(|| {
+            let a = random_symmetric(BENCH_SIZE);
+
+            let (t, _) = schur_form_opts(a.view(), &opts).unwrap();
+            black_box(&t);
+        })
+    }
+
+    #[bench]
+    fn bench_lanczos(b: &mut Bencher) {
+        b.iter(|| {
+            let a = random_symmetric(BENCH_SIZE);
+
+            let (z, _, _) = lanczos(&a, 10, LanczosTarget::Largest).unwrap();
+            black_box(&z);
+        })
+    }
+
     #[bench]
     fn bench_svd(b: &mut Bencher) {
         b.iter(|| {
@@ -0,0 +1,205 @@
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const BISECTION_ITERATIONS: usize = 256;
+const INVERSE_ITERATIONS: usize = 5;
+// eigenvalues closer than this (relative to the norm) have their eigenvectors reorthogonalized
+const CLUSTER_GAP: f64 = 1e-3;
+
+#[inline]
+fn pivot_min(e: VectorView) -> f64 {
+    f64::MIN_POSITIVE * e.iter().fold(1., |acc: f64, x| acc.max(x * x))
+}
+
+pub fn tridiagonal_norm(d: VectorView, e: VectorView) -> f64 {
+    let n = d.shape()[0];
+    (0..n)
+        .map(|i| {
+            let l = if i > 0 { e[i - 1].abs() } else { 0. };
+            let r = if i + 1 < n { e[i].abs() } else { 0. };
+            d[i].abs() + l + r
+        })
+        .fold(0., f64::max)
+}
+
+// Number of eigenvalues less than x, from the signs of the LDL^T pivots of T - xI
+pub fn sturm_count(d: VectorView, e: VectorView, x: f64) -> usize {
+    let pivmin = pivot_min(e);
+    let mut count = 0;
+    let mut q = 1.;
+
+    for i in 0..d.shape()[0] {
+        q = d[i] - x - if i > 0 { e[i - 1] * e[i - 1] / q } else { 0. };
+        if q.abs() < pivmin {
+            q = -pivmin;
+        }
+        if q < 0. {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+// k-th smallest eigenvalue (counting from 0) by Sturm sequence bisection on the Gershgorin interval
+pub fn bisection(d: VectorView, e: VectorView, k: usize) -> f64 {
+    let norm = tridiagonal_norm(d, e);
+    let (mut lo, mut hi) = (-norm, norm);
+
+    for _ in 0..BISECTION_ITERATIONS {
+        let tol = 2. * f64::EPSILON * lo.abs().max(hi.abs()) + f64::EPSILON * norm + pivot_min(e);
+        if hi - lo <= tol {
+            break;
+        }
+
+        let mid = 0.5 * (lo + hi);
+        if sturm_count(d, e, mid) > k {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+// Gaussian elimination with partial pivoting of T - lI as in LAPACK gttrf:
+// U has two superdiagonals, row i is interchanged with row i + 1 if swap[i]
+struct ShiftedLU {
+    u0: Vec<f64>,
+    u1: Vec<f64>,
+    u2: Vec<f64>,
+    mult: Vec<f64>,
+    swap: Vec<bool>,
+}
+
+fn shifted_lu(d: VectorView, e: VectorView, l: f64, tiny: f64) -> ShiftedLU {
+    let n = d.shape()[0];
+    let mut lu = ShiftedLU {
+        u0: d.iter().map(|x| x - l).collect(),
+        u1: e.to_vec(),
+        u2: vec![0.; n.saturating_sub(2)],
+        mult: e.to_vec(),
+        swap: vec![false; n.saturating_sub(1)],
+    };
+    let ShiftedLU {
+        u0,
+        u1,
+        u2,
+        mult,
+        swap,
+    } = &mut lu;
+
+    for i in 0..n.saturating_sub(1) {
+        if u0[i].abs() >= mult[i].abs() {
+            if u0[i] == 0. {
+                u0[i] = tiny;
+            }
+            mult[i] /= u0[i];
+            u0[i + 1] -= mult[i] * u1[i];
+        } else {
+            let fact = u0[i] / mult[i];
+            u0[i] = mult[i];
+            mult[i] = fact;
+            let temp = u1[i];
+            u1[i] = u0[i + 1];
+            u0[i + 1] = temp - fact * u0[i + 1];
+            if i + 2 < n {
+                u2[i] = u1[i + 1];
+                u1[i + 1] *= -fact;
+            }
+            swap[i] = true;
+        }
+    }
+
+    // T - lI is nearly singular by construction, keep the back substitution finite
+    for x in u0.iter_mut() {
+        if x.abs() < tiny {
+            *x = tiny.copysign(*x);
+        }
+    }
+
+    lu
+}
+
+fn shifted_solve(lu: &ShiftedLU, mut b: VectorViewMut) {
+    let n = b.shape()[0];
+
+    for i in 0..n.saturating_sub(1) {
+        if lu.swap[i] {
+            let temp = b[i];
+            b[i] = b[i + 1];
+            b[i + 1] = temp - lu.mult[i] * b[i];
+        } else {
+            b[i + 1] -= lu.mult[i] * b[i];
+        }
+    }
+
+    for i in (0..n).rev() {
+        let mut x = b[i];
+        if i + 1 < n {
+            x -= lu.u1[i] * b[i + 1];
+        }
+        if i + 2 < n {
+            x -= lu.u2[i] * b[i + 2];
+        }
+        b[i] = x / lu.u0[i];
+    }
+}
+
+// Eigenvectors for the increasing eigenvalues z by inverse iteration,
+// reorthogonalized within clusters of close eigenvalues
+pub fn inverse_iteration(
+    d: VectorView,
+    e: VectorView,
+    z: VectorView,
+    opts: &QROptions,
+) -> Option<Matrix> {
+    let (n, k) = (d.shape()[0], z.shape()[0]);
+    let norm = tridiagonal_norm(d, e).max(f64::MIN_POSITIVE);
+    let tiny = f64::EPSILON * norm;
+    let mut x = Matrix::zeros((n, k));
+    let (mut cluster, mut prev) = (0, f64::NEG_INFINITY);
+
+    for j in 0..k {
+        // perturb equal eigenvalues so that the iterations are distinct
+        let mut l = z[j];
+        if j > 0 && l - prev < 10. * tiny {
+            l = prev + 10. * tiny;
+        }
+        if j > 0 && l - prev > CLUSTER_GAP * norm {
+            cluster = j;
+        }
+        prev = l;
+
+        let lu = shifted_lu(d, e, l, tiny);
+        let mut v: Vector = Array::random(n, Uniform::new(-1., 1.));
+        let mut converged = false;
+
+        for _ in 0..INVERSE_ITERATIONS {
+            shifted_solve(&lu, v.view_mut());
+            for c in cluster..j {
+                let proj = x.column(c).dot(&v);
+                v.scaled_add(-proj, &x.column(c));
+            }
+
+            // the residual of the previous iterate is 1 / |v|
+            let growth = v.dot(&v).sqrt();
+            v /= growth;
+            if converged {
+                break;
+            }
+            converged = growth * opts.eps * norm >= 1.;
+        }
+
+        if !converged {
+            return None;
+        }
+        x.column_mut(j).assign(&v);
+    }
+
+    Some(x)
+}
@@ -30,3 +30,56 @@ pub fn rot_2by2(m: MatrixView) -> (f64, f64) {
         rot_2by2_real(m, (d.sqrt() + p) / 2.)
     }
 }
+
+pub fn solve_block_system(mut a: Matrix, mut b: Vector) -> Vector {
+    let n = b.shape()[0];
+    let amax = a.iter().fold(0., |acc: f64, x| acc.max(x.abs()));
+    let smin = (f64::EPSILON * amax).max(f64::MIN_POSITIVE);
+    let mut cols: Vec<usize> = (0..n).collect();
+
+    for k in 0..n {
+        let (mut pi, mut pj) = (k, k);
+        for i in k..n {
+            for j in k..n {
+                if a[[i, j]].abs() > a[[pi, pj]].abs() {
+                    pi = i;
+                    pj = j;
+                }
+            }
+        }
+
+        for j in 0..n {
+            a.swap([k, j], [pi, j]);
+        }
+        b.swap(k, pi);
+        for i in 0..n {
+            a.swap([i, k], [i, pj]);
+        }
+        cols.swap(k, pj);
+
+        if a[[k, k]].abs() < smin {
+            a[[k, k]] = smin;
+        }
+
+        for i in k + 1..n {
+            let l = a[[i, k]] / a[[k, k]];
+            for j in k..n {
+                a[[i, j]] -= l * a[[k, j]];
+            }
+            b[i] -= l * b[k];
+        }
+    }
+
+    for k in (0..n).rev() {
+        for j in k + 1..n {
+            b[k] -= a[[k, j]] * b[j];
+        }
+        b[k] /= a[[k, k]];
+    }
+
+    let mut x = Vector::zeros(n);
+    for k in 0..n {
+        x[cols[k]] = b[k];
+    }
+    x
+}
@@ -5,8 +5,11 @@ pub fn frob_norm(m: MatrixView) -> f64 {
 }
 
 pub fn diff_rel(orig: MatrixView, res: MatrixView) -> f64 {
-    let diff = &res - &orig;
-    frob_norm(diff.view()) / frob_norm(orig.view())
+    let diff = frob_norm((&res - &orig).view());
+    if diff == 0. {
+        return 0.;
+    }
+    diff / frob_norm(orig.view())
 }
 
 #[allow(dead_code)]
@@ -0,0 +1,73 @@
+use crate::*;
+
+use ndarray::{s, Axis};
+
+pub fn cholesky(mut m: MatrixViewMut) -> std::result::Result<(), usize> {
+    let n = m.shape()[0];
+    for j in 0..n {
+        let mut d = m[[j, j]];
+        for k in 0..j {
+            d -= m[[j, k]] * m[[j, k]];
+        }
+
+        if d.is_nan() || d <= 0. {
+            return Err(j);
+        }
+
+        let d = d.sqrt();
+        m[[j, j]] = d;
+        for i in j + 1..n {
+            let mut x = m[[i, j]];
+            for k in 0..j {
+                x -= m[[i, k]] * m[[j, k]];
+            }
+            m[[i, j]] = x / d;
+        }
+
+        for i in 0..j {
+            m[[i, j]] = 0.;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn cholesky_pivoted(m: MatrixView, tol: f64) -> std::result::Result<Matrix, usize> {
+    // M = F F^T with F of rank(M) columns; pivots below tol relative to the largest diagonal entry end the factorization
+    let n = m.shape()[0];
+    let mut r = m.into_owned();
+    let mut f = Matrix::zeros((n, n));
+    let dmax = r.diag().iter().cloned().fold(0., f64::max);
+
+    let mut rank = 0;
+    while rank < n {
+        let (p, d) =
+            r.diag()
+                .iter()
+                .cloned()
+                .enumerate()
+                .fold(
+                    (0, f64::NEG_INFINITY),
+                    |acc, (i, x)| if x > acc.1 { (i, x) } else { acc },
+                );
+        if d <= tol * dmax {
+            break;
+        }
+
+        let col = r.column(p).to_owned() / d.sqrt();
+        r -= &col
+            .view()
+            .insert_axis(Axis(1))
+            .dot(&col.view().insert_axis(Axis(0)));
+        f.column_mut(rank).assign(&col);
+        rank += 1;
+    }
+
+    // Entries of a positive semidefinite remainder are bounded by its largest diagonal entry
+    let bound = tol * dmax;
+    if let Some(i) = (0..n).find(|&i| r.row(i).iter().any(|x| x.is_nan() || x.abs() > bound)) {
+        return Err(i);
+    }
+
+    Ok(f.slice(s![.., 0..rank]).into_owned())
+}
@@ -22,13 +22,13 @@ pub fn stack_owned(axis: Axis, vs: &[Vector]) -> Matrix {
 
 #[inline]
 pub fn eigval_collapsed(eps: f64, subdiag: f64, upper: f64, lower: f64) -> bool {
-    subdiag.abs() < eps * (upper.abs() + lower.abs())
+    subdiag.abs() <= eps * (upper.abs() + lower.abs())
 }
 
 pub fn sort_diagonal_values(mut z: VectorViewMut, mut u: MatrixViewMut) {
     let mut perm: Vec<usize> = (0..z.shape()[0]).collect();
     perm.sort_by(|i1, i2| z[[*i2]].partial_cmp(&z[[*i1]]).unwrap_or(Ordering::Equal));
-    let new_z: Vector = (&perm).iter().map(|i| z[[*i]]).collect();
+    let new_z: Vector = perm.iter().map(|i| z[[*i]]).collect();
     let cols: Vec<VectorView> = perm.iter().map(|i| u.column(*i)).collect();
     let new_u: Matrix = stack(Axis(1), cols.as_slice()).unwrap();
     z.assign(&new_z);
@@ -45,6 +45,28 @@ pub fn orthonormalize(orth: &mut [Vector]) {
     }
 }
 
+pub fn orthonormal_basis(vs: &[Vector], k: usize, eps: f64) -> Vec<Vector> {
+    let mut basis: Vec<Vector> = Vec::with_capacity(k);
+    for v in vs {
+        if basis.len() == k {
+            break;
+        }
+
+        let mut w = v.clone();
+        for _ in 0..2 {
+            for b in basis.iter() {
+                w -= &proj(w.view(), b.view());
+            }
+        }
+
+        let nw = norm(w.view());
+        if nw > eps * norm(v.view()) {
+            basis.push(w / nw);
+        }
+    }
+    basis
+}
+
 pub fn zero_subeps_entries(mut m: MatrixViewMut, eps: f64) {
     m.map_inplace(|x| {
         if x.abs() < eps {
@@ -53,21 +75,45 @@ pub fn zero_subeps_entries(mut m: MatrixViewMut, eps: f64) {
     })
 }
 
+pub fn schur_blocks(m: MatrixView) -> Vec<(usize, usize)> {
+    let (mut i, n) = (0, m.shape()[0]);
+    let mut blocks = Vec::new();
+
+    while i < n {
+        let sz = if i + 1 < n && m[[i + 1, i]] != 0. {
+            2
+        } else {
+            1
+        };
+        blocks.push((i, sz));
+        i += sz;
+    }
+
+    blocks
+}
+
 pub fn extract_eigenvalues(m: MatrixView) -> Vec<Complex> {
     let (mut i, n) = (0, m.shape()[0]);
     let mut eigs = Vec::new();
 
-    while i + 1 < n {
+    while i < n {
         let re = m[[i, i]];
-        if m[[i + 1, i]] == 0. {
+        if i + 1 == n || m[[i + 1, i]] == 0. {
             eigs.push(Complex::from(re));
             i += 1;
             continue;
         }
 
-        let im = (m[[i + 1, i]] * m[[i, i + 1]]).sqrt();
-        eigs.push(Complex::new(re, im));
-        eigs.push(Complex::new(re, -im));
+        let p = 0.5 * (re + m[[i + 1, i + 1]]);
+        let h = 0.5 * (re - m[[i + 1, i + 1]]);
+        let d = h * h + m[[i + 1, i]] * m[[i, i + 1]];
+        if d < 0. {
+            eigs.push(Complex::new(p, (-d).sqrt()));
+            eigs.push(Complex::new(p, -(-d).sqrt()));
+        } else {
+            eigs.push(Complex::from(p + d.sqrt()));
+            eigs.push(Complex::from(p - d.sqrt()));
+        }
         i += 2;
     }
 
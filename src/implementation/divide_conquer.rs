@@ -0,0 +1,280 @@
+use crate::implementation::qr_symmetric::qr_algorithm_tridiagonal;
+use crate::*;
+
+use ndarray::{concatenate, s, Axis};
+use std::cmp::Ordering;
+
+const LEAF_SIZE: usize = 16;
+const SECULAR_ITERATIONS: usize = 200;
+
+// Root of the secular equation stored as an offset from its closest pole,
+// so that the differences to the poles are computed without cancellation
+struct SecularRoot {
+    origin: usize,
+    tau: f64,
+}
+
+impl SecularRoot {
+    #[inline]
+    fn minus_pole(&self, d: &[f64], i: usize) -> f64 {
+        (d[self.origin] - d[i]) + self.tau
+    }
+}
+
+// (f, psi, psi', phi, phi', sum of |terms|) of f(l) = 1 + rho sum z_i^2 / (d_i - l) at l = d[origin] + tau,
+// where psi sums the poles up to j and phi the rest
+#[inline]
+fn secular_terms(
+    d: &[f64],
+    z: &[f64],
+    rho: f64,
+    origin: usize,
+    tau: f64,
+    j: usize,
+) -> (f64, f64, f64, f64, f64, f64) {
+    let (mut psi, mut dpsi, mut phi, mut dphi, mut abs) = (0., 0., 0., 0., 0.);
+    for i in 0..d.len() {
+        let delta = (d[i] - d[origin]) - tau;
+        let t = rho * z[i] * z[i] / delta;
+        if i <= j {
+            psi += t;
+            dpsi += t / delta;
+        } else {
+            phi += t;
+            dphi += t / delta;
+        }
+        abs += t.abs();
+    }
+    (1. + psi + phi, psi, dpsi, phi, dphi, abs)
+}
+
+// Zero of c + s1 / (a1 - x) + s2 / (a2 - x) strictly inside (lo, hi), if any
+#[inline]
+fn rational_step(
+    c: f64,
+    a1: f64,
+    s1: f64,
+    a2: Option<f64>,
+    s2: f64,
+    lo: f64,
+    hi: f64,
+) -> Option<f64> {
+    let inside = |x: f64| x > lo && x < hi;
+    let a2 = match a2 {
+        Some(a2) => a2,
+        None => return Some(a1 + s1 / c).filter(|x| inside(*x)),
+    };
+
+    let b = -(c * (a1 + a2) + s1 + s2);
+    let cc = c * a1 * a2 + s1 * a2 + s2 * a1;
+    if c == 0. {
+        return Some(-cc / b).filter(|x| inside(*x));
+    }
+
+    let disc = b * b - 4. * c * cc;
+    if disc < 0. {
+        return None;
+    }
+    let q = -0.5 * (b + b.signum() * disc.sqrt());
+    [q / c, cc / q].iter().cloned().find(|x| inside(*x))
+}
+
+// j-th root of 1 + rho sum z_i^2 / (d_i - l) with increasing distinct poles d and rho > 0,
+// by the rational two-pole interpolation safeguarded with bisection
+fn secular_root(d: &[f64], z: &[f64], rho: f64, j: usize) -> SecularRoot {
+    let k = d.len();
+    let (origin, mut lo, mut hi) = if j + 1 == k {
+        (j, 0., rho * z.iter().map(|x| x * x).sum::<f64>())
+    } else {
+        let half = (d[j + 1] - d[j]) / 2.;
+        if secular_terms(d, z, rho, j, half, j).0 >= 0. {
+            (j, 0., half)
+        } else {
+            (j + 1, -half, 0.)
+        }
+    };
+
+    let a1 = d[j] - d[origin];
+    let a2 = if j + 1 < k {
+        Some(d[j + 1] - d[origin])
+    } else {
+        None
+    };
+    let mut tau = if origin == j { hi } else { lo };
+
+    for _ in 0..SECULAR_ITERATIONS {
+        let (f, psi, dpsi, phi, dphi, abs) = secular_terms(d, z, rho, origin, tau, j);
+        if f.abs() <= 8. * f64::EPSILON * (1. + abs) {
+            break;
+        }
+        if f < 0. {
+            lo = tau;
+        } else {
+            hi = tau;
+        }
+        if hi - lo <= 2. * f64::EPSILON * lo.abs().max(hi.abs()) {
+            break;
+        }
+
+        let s1 = dpsi * (a1 - tau) * (a1 - tau);
+        let (c2, s2) = match a2 {
+            Some(a2) => (phi - dphi * (a2 - tau), dphi * (a2 - tau) * (a2 - tau)),
+            None => (0., 0.),
+        };
+        let c = 1. + psi - dpsi * (a1 - tau) + c2;
+
+        let next = rational_step(c, a1, s1, a2, s2, lo, hi).unwrap_or((lo + hi) / 2.);
+        if next == tau {
+            break;
+        }
+        tau = next;
+    }
+
+    SecularRoot { origin, tau }
+}
+
+// Eigendecomposition of D + rho z z^T with the eigenvectors premultiplied by q
+fn rank_one_update(mut d: Vector, mut q: Matrix, mut z: Vector, mut rho: f64) -> (Vector, Matrix) {
+    if rho < 0. {
+        let (l, u) = rank_one_update(-d, q, z, -rho);
+        return (-l, u);
+    }
+
+    let norm = z.dot(&z);
+    if rho == 0. || norm == 0. {
+        return (d, q);
+    }
+    z /= norm.sqrt();
+    rho *= norm;
+
+    let mut order: Vec<usize> = (0..d.len()).collect();
+    order.sort_by(|i, j| d[*i].partial_cmp(&d[*j]).unwrap_or(Ordering::Equal));
+    let tol = 8. * f64::EPSILON * d.iter().fold(rho, |acc, x| acc.max(x.abs()));
+
+    // deflate negligible components of z and pairs of close poles
+    let (mut kept, mut prev) = (Vec::new(), None);
+    for &i in order.iter() {
+        if rho * z[i].abs() <= tol {
+            continue;
+        }
+
+        if let Some(p) = prev {
+            let r = z[p].hypot(z[i]);
+            let (c, s) = (z[i] / r, z[p] / r);
+            if ((d[i] - d[p]) * c * s).abs() <= tol {
+                let (qp, qi) = (q.column(p).to_owned(), q.column(i).to_owned());
+                q.column_mut(p).assign(&(c * &qp - s * &qi));
+                q.column_mut(i).assign(&(s * &qp + c * &qi));
+
+                let (dp, di) = (d[p], d[i]);
+                d[p] = c * c * dp + s * s * di;
+                d[i] = s * s * dp + c * c * di;
+                z[p] = 0.;
+                z[i] = r;
+            } else {
+                kept.push(p);
+            }
+        }
+        prev = Some(i);
+    }
+    kept.extend(prev);
+    kept.sort_by(|i, j| d[*i].partial_cmp(&d[*j]).unwrap_or(Ordering::Equal));
+
+    let kd: Vec<f64> = kept.iter().map(|i| d[*i]).collect();
+    let kz: Vec<f64> = kept.iter().map(|i| z[*i]).collect();
+    let k = kept.len();
+    let roots: Vec<SecularRoot> = (0..k).map(|j| secular_root(&kd, &kz, rho, j)).collect();
+
+    // recompute z from the computed roots (Gu, Eisenstat) so that the eigenvectors are orthogonal
+    let zhat: Vec<f64> = (0..k)
+        .map(|i| {
+            let mut prod = roots[k - 1].minus_pole(&kd, i) / rho;
+            for j in 0..k - 1 {
+                let pole = if j < i { kd[j] } else { kd[j + 1] };
+                prod *= roots[j].minus_pole(&kd, i) / (pole - kd[i]);
+            }
+            prod.abs().sqrt().copysign(kz[i])
+        })
+        .collect();
+
+    let mut v = Matrix::zeros((k, k));
+    for (j, root) in roots.iter().enumerate() {
+        let mut col = v.column_mut(j);
+        for i in 0..k {
+            col[i] = -zhat[i] / root.minus_pole(&kd, i);
+        }
+        let norm = col.dot(&col).sqrt();
+        col /= norm;
+    }
+
+    let mut is_kept = vec![false; d.len()];
+    kept.iter().for_each(|i| is_kept[*i] = true);
+    let deflated: Vec<usize> = (0..d.len()).filter(|i| !is_kept[*i]).collect();
+    let l: Vector = deflated
+        .iter()
+        .map(|i| d[*i])
+        .chain(roots.iter().map(|r| kd[r.origin] + r.tau))
+        .collect();
+    let u = concatenate(
+        Axis(1),
+        &[
+            q.select(Axis(1), &deflated).view(),
+            q.select(Axis(1), &kept).dot(&v).view(),
+        ],
+    )
+    .unwrap();
+    (l, u)
+}
+
+pub fn divide_and_conquer(mut d: VectorViewMut, e: VectorView, opts: &QROptions) -> Option<Matrix> {
+    let n = d.shape()[0];
+    if n <= LEAF_SIZE {
+        let mut e = e.into_owned();
+        let mut u = Matrix::eye(n);
+        return if qr_algorithm_tridiagonal(d, e.view_mut(), Some(u.view_mut()), opts) {
+            Some(u)
+        } else {
+            None
+        };
+    }
+
+    // T = diag(T1, T2) + beta v v^T with v = (e_k, e_1)
+    let k = n / 2;
+    let beta = e[k - 1];
+    d[k - 1] -= beta;
+    d[k] -= beta;
+    let q1 = divide_and_conquer(d.slice_mut(s![..k]), e.slice(s![..k - 1]), opts)?;
+    let q2 = divide_and_conquer(d.slice_mut(s![k..]), e.slice(s![k..]), opts)?;
+
+    let mut q = Matrix::zeros((n, n));
+    q.slice_mut(s![..k, ..k]).assign(&q1);
+    q.slice_mut(s![k.., k..]).assign(&q2);
+    let z = concatenate(Axis(0), &[q1.row(k - 1), q2.row(0)]).unwrap();
+
+    let (l, u) = rank_one_update(d.to_owned(), q, z, beta);
+    d.assign(&l);
+    Some(u)
+}
+
+pub fn divide_and_conquer_symmetric(
+    mut m: MatrixViewMut,
+    mut u: MatrixViewMut,
+    opts: &QROptions,
+) -> bool {
+    let n = m.shape()[0];
+    let mut d = m.diag().into_owned();
+    let e: Vector = (1..n).map(|k| m[[k, k - 1]]).collect();
+
+    let q = match divide_and_conquer(d.view_mut(), e.view(), opts) {
+        Some(q) => q,
+        None => return false,
+    };
+
+    m.fill(0.);
+    m.diag_mut().assign(&d);
+    if opts.accumulate_sim_transforms {
+        let uq = u.dot(&q);
+        u.assign(&uq);
+    }
+    true
+}
@@ -0,0 +1,93 @@
+// passes allowed per eigenvalue before giving up
+const PASSES_PER_EIGENVALUE: usize = 100;
+// fractions of the smallest d tried as the next shift
+const SHIFT_FRACTIONS: [f64; 4] = [0.99, 0.75, 0.25, 0.];
+
+// One dqds transform of the first n entries with shift s, writing into (qs, es);
+// outputs (min d, min d without the last one) or None if the shift is too large or d is not a number
+fn dqds_transform(
+    q: &[f64],
+    e: &[f64],
+    qs: &mut [f64],
+    es: &mut [f64],
+    n: usize,
+    s: f64,
+) -> Option<(f64, f64)> {
+    let mut d = q[0] - s;
+    let mut dmin = d;
+    let mut dmin2 = f64::INFINITY;
+
+    for i in 0..n - 1 {
+        if d.is_nan() || d < 0. {
+            return None;
+        }
+        dmin2 = dmin2.min(d);
+        qs[i] = d + e[i];
+        if qs[i] == 0. {
+            // both d and e vanish, the array splits here
+            es[i] = 0.;
+            d = q[i + 1] - s;
+        } else {
+            // d / qs and e / qs are at most 1, so nothing overflows when qs is tiny
+            es[i] = e[i] / qs[i] * q[i + 1];
+            d = d / qs[i] * q[i + 1] - s;
+        }
+        dmin = dmin.min(d);
+    }
+
+    if d.is_nan() || d < 0. {
+        return None;
+    }
+    qs[n - 1] = d;
+    Some((dmin, dmin2))
+}
+
+// Eigenvalues of B^T B for the upper bidiagonal B given by the squares q_i = b_ii^2 and e_i = b_i,i+1^2,
+// computed to high relative accuracy by the differential quotient-difference algorithm with shifts (Fernando, Parlett).
+// The eigenvalues replace q in no particular order; outputs false if the iteration did not converge
+pub fn dqds(q: &mut [f64], e: &mut [f64]) -> bool {
+    let tol2 = (100. * f64::EPSILON) * (100. * f64::EPSILON);
+    let mut qs = vec![0.; q.len()];
+    let mut es = vec![0.; e.len()];
+    let (mut sigma, mut dmin) = (0., 0.);
+    let mut end = q.len();
+    let mut passes = 0;
+
+    while end > 0 {
+        if end == 1 {
+            q[0] += sigma;
+            break;
+        }
+
+        let last = e[end - 2];
+        if last <= tol2 * (sigma + q[end - 1]) {
+            q[end - 1] += sigma;
+            end -= 1;
+            continue;
+        }
+
+        if passes >= PASSES_PER_EIGENVALUE * q.len() {
+            return false;
+        }
+        passes += 1;
+
+        for frac in SHIFT_FRACTIONS.iter() {
+            let s = frac * dmin;
+            if let Some((d1, d2)) = dqds_transform(q, e, &mut qs, &mut es, end, s) {
+                q[..end].copy_from_slice(&qs[..end]);
+                e[..end - 1].copy_from_slice(&es[..end - 1]);
+                sigma += s;
+                // after a deflation the last entry is gone, so its d no longer bounds the rest
+                let next_last = e[end - 2];
+                dmin = if next_last <= tol2 * (sigma + q[end - 1]) {
+                    d2
+                } else {
+                    d1
+                };
+                break;
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,124 @@
+use crate::implementation::lu::{lu_decomposition, lu_solve};
+use crate::*;
+
+const PADE_3: [f64; 4] = [120., 60., 12., 1.];
+const PADE_5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const PADE_7: [f64; 8] = [
+    17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.,
+    8821612800.,
+    2075673600.,
+    302702400.,
+    30270240.,
+    2162160.,
+    110880.,
+    3960.,
+    90.,
+    1.,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+// Largest 1-norms for which the backward error of the degree m approximant is below unit roundoff
+const THETA: [(usize, f64); 4] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068),
+];
+const THETA_13: f64 = 5.371920351148152;
+
+pub fn norm_1(m: MatrixView) -> f64 {
+    m.gencolumns()
+        .into_iter()
+        .map(|c| c.iter().map(|x| x.abs()).sum::<f64>())
+        .fold(0., f64::max)
+}
+
+fn pade_solve(u: Matrix, v: Matrix) -> Matrix {
+    let mut q = &v - &u;
+    let p = &v + &u;
+    let (perm, _) = lu_decomposition(q.view_mut());
+    lu_solve(q.view(), &perm, p.view())
+}
+
+fn pade_low(a: MatrixView, b: &[f64]) -> Matrix {
+    let n = a.shape()[0];
+    let a2 = a.dot(&a);
+    let mut pw = Matrix::eye(n);
+    let mut u = Matrix::zeros((n, n));
+    let mut v = Matrix::zeros((n, n));
+    for k in (0..b.len()).step_by(2) {
+        v.scaled_add(b[k], &pw);
+        u.scaled_add(b[k + 1], &pw);
+        pw = pw.dot(&a2);
+    }
+    pade_solve(a.dot(&u), v)
+}
+
+fn pade_13(a: MatrixView) -> Matrix {
+    let b = &PADE_13;
+    let n = a.shape()[0];
+    let eye = Matrix::eye(n);
+    let a2 = a.dot(&a);
+    let a4 = a2.dot(&a2);
+    let a6 = a4.dot(&a2);
+
+    let u1 = b[13] * &a6 + b[11] * &a4 + b[9] * &a2;
+    let u2 = b[7] * &a6 + b[5] * &a4 + b[3] * &a2 + b[1] * &eye;
+    let u = a.dot(&(a6.dot(&u1) + u2));
+
+    let v1 = b[12] * &a6 + b[10] * &a4 + b[8] * &a2;
+    let v2 = b[6] * &a6 + b[4] * &a4 + b[2] * &a2 + b[0] * &eye;
+    let v = a6.dot(&v1) + v2;
+
+    pade_solve(u, v)
+}
+
+pub fn expm_pade(a: MatrixView) -> Matrix {
+    let norm = norm_1(a);
+    for &(m, theta) in THETA.iter() {
+        if norm <= theta {
+            let b: &[f64] = match m {
+                3 => &PADE_3,
+                5 => &PADE_5,
+                7 => &PADE_7,
+                _ => &PADE_9,
+            };
+            return pade_low(a, b);
+        }
+    }
+
+    let s = (norm / THETA_13).log2().ceil().max(0.) as i32;
+    let scaled = a.to_owned() / 2f64.powi(s);
+    let mut r = pade_13(scaled.view());
+    for _ in 0..s {
+        r = r.dot(&r);
+    }
+    r
+}
+
+pub fn symmetric_function(t: MatrixView, u: MatrixView, f: impl Fn(f64) -> f64) -> Matrix {
+    let d: Vector = t.diag().iter().map(|x| f(*x)).collect();
+    let mut fu = u.into_owned();
+    for (i, mut col) in fu.gencolumns_mut().into_iter().enumerate() {
+        col *= d[i];
+    }
+    fu.dot(&u.t())
+}
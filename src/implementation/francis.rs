@@ -8,24 +8,41 @@ use ndarray::{array, s};
 use std::cmp::{max, min};
 
 #[inline]
-fn francis_reflection_axis(m: MatrixView, p: usize) -> Vector {
+fn francis_reflection_axis(m: MatrixView, l: usize, p: usize, exceptional: bool) -> Vector {
     let q = p - 1;
-    let trace = m[[q, q]] + m[[p, p]];
-    let det = m[[q, q]] * m[[p, p]] - m[[q, p]] * m[[p, q]];
+    let (mut trace, mut det) = (
+        m[[q, q]] + m[[p, p]],
+        m[[q, q]] * m[[p, p]] - m[[q, p]] * m[[p, q]],
+    );
+
+    if exceptional {
+        // Ad hoc shifts of LAPACK dlahqr, centred on the trailing diagonal entry
+        let w = m[[p, q]].abs() + m[[q, q - 1]].abs();
+        let (h11, h12, h21) = (0.75 * w + m[[p, p]], -0.4375 * w, w);
+        trace = 2. * h11;
+        det = h11 * h11 - h12 * h21;
+    }
 
-    let x = m[[0, 0]] * m[[0, 0]] + m[[0, 1]] * m[[1, 0]] - trace * m[[0, 0]] + det;
-    let y = m[[1, 0]] * (m[[0, 0]] + m[[1, 1]] - trace);
-    let z = m[[1, 0]] * m[[2, 1]];
+    let (a, b) = (l, l + 1);
+    let x = m[[a, a]] * m[[a, a]] + m[[a, b]] * m[[b, a]] - trace * m[[a, a]] + det;
+    let y = m[[b, a]] * (m[[a, a]] + m[[b, b]] - trace);
+    let z = m[[b, a]] * m[[b + 1, b]];
 
     array![x, y, z]
 }
 
-fn francis_qr_step(mut m: MatrixViewMut, mut u: MatrixViewMut, mut v: Vector, p: usize, acc: bool) {
+fn francis_qr_step(
+    mut m: MatrixViewMut,
+    mut u: MatrixViewMut,
+    mut v: Vector,
+    (l, p): (usize, usize),
+    acc: bool,
+) {
     let n = m.shape()[0];
-    for k in 0..p - 1 {
+    for k in l..p - 1 {
         let refl = householder_vec(v.view());
 
-        let r = max(1, k);
+        let r = max(l + 1, k);
         householder_refl_left(refl.view(), m.slice_mut(s![k..k + 3, r - 1..n]));
 
         let r = min(k + 4, p + 1);
@@ -54,26 +71,38 @@ fn francis_qr_step(mut m: MatrixViewMut, mut u: MatrixViewMut, mut v: Vector, p:
 pub fn qr_algorithm_francis(mut m: MatrixViewMut, mut u: MatrixViewMut, opts: &QROptions) {
     let n = m.shape()[0];
     let mut p = n - 1;
-    let mut i = 0;
+    let (mut i, mut since_deflation) = (0, 0);
 
     while p > 1 && i < opts.iterations {
         let q = p - 1;
         if eigval_collapsed(opts.eps, m[[p, q]], m[[q, q]], m[[p, p]]) {
             m[[p, q]] = 0.;
             p -= 1;
+            since_deflation = 0;
             continue;
         } else if eigval_collapsed(opts.eps, m[[p - 1, q - 1]], m[[q - 1, q - 1]], m[[q, q]]) {
             m[[p - 1, q - 1]] = 0.;
             p -= 2;
+            since_deflation = 0;
             continue;
         }
 
-        let v = francis_reflection_axis(m.view(), p);
+        // The step acts on the trailing unreduced block only
+        let l = (1..q)
+            .rev()
+            .find(|&j| eigval_collapsed(opts.eps, m[[j, j - 1]], m[[j - 1, j - 1]], m[[j, j]]))
+            .unwrap_or(0);
+        if l > 0 {
+            m[[l, l - 1]] = 0.;
+        }
+
+        since_deflation += 1;
+        let v = francis_reflection_axis(m.view(), l, p, since_deflation % 10 == 0);
         francis_qr_step(
             m.view_mut(),
             u.view_mut(),
             v,
-            p,
+            (l, p),
             opts.accumulate_sim_transforms,
         );
 
@@ -31,11 +31,11 @@ pub fn qr_algorithm_hessenberg(mut m: MatrixViewMut, mut u: MatrixViewMut, opts:
             givens_rot_left(gv[k], m.slice_mut(s![k..k + 2, k..n]));
         }
 
-        for k in 0..n - 1 {
-            givens_rot_right(gv[k], m.slice_mut(s![0..k + 2, k..k + 2]));
+        for (k, &rot) in gv.iter().enumerate() {
+            givens_rot_right(rot, m.slice_mut(s![0..k + 2, k..k + 2]));
 
             if opts.accumulate_sim_transforms {
-                givens_rot_right(gv[k], u.slice_mut(s![0..n, k..k + 2]));
+                givens_rot_right(rot, u.slice_mut(s![0..n, k..k + 2]));
             }
         }
     }
@@ -0,0 +1,160 @@
+use crate::implementation::common::{orthonormal_basis, stack_owned};
+use crate::*;
+
+use ndarray::{s, Axis};
+use std::cmp::Ordering;
+
+// tan of the rotation angle zeroing the off-diagonal entry of [[app, apq], [apq, aqq]]
+#[inline]
+pub fn jacobi_tangent(app: f64, aqq: f64, apq: f64) -> f64 {
+    let theta = (aqq - app) / (2. * apq);
+    if theta.abs() > 1e150 {
+        0.5 / theta
+    } else {
+        theta.signum() / (theta.abs() + theta.hypot(1.))
+    }
+}
+
+#[inline]
+fn rotate_columns(mut m: MatrixViewMut, p: usize, q: usize, c: f64, s: f64) {
+    for mut row in m.genrows_mut() {
+        let (x, y) = (row[p], row[q]);
+        row[p] = c * x - s * y;
+        row[q] = s * x + c * y;
+    }
+}
+
+#[inline]
+fn scaled_off_norm(m: MatrixView) -> f64 {
+    let n = m.shape()[0];
+    let mut off = 0.;
+    for p in 0..n {
+        for q in 0..n {
+            if p != q && m[[p, q]] != 0. {
+                off += m[[p, q]] * m[[p, q]] / (m[[p, p]] * m[[q, q]]).abs();
+            }
+        }
+    }
+    off.sqrt()
+}
+
+// Cyclic Jacobi on a symmetric matrix; a rotation is skipped if |a_pq| <= eps sqrt(|a_pp a_qq|),
+// which keeps small eigenvalues of graded matrices relatively accurate.
+// Outputs (info, converged)
+pub fn jacobi_eigen(
+    mut m: MatrixViewMut,
+    mut u: MatrixViewMut,
+    opts: &QROptions,
+) -> (JacobiInfo, bool) {
+    let n = m.shape()[0];
+    let mut sweeps = 0;
+    let mut converged = false;
+
+    while !converged && sweeps < opts.iterations {
+        converged = true;
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = m[[p, q]];
+                if apq == 0. || apq.abs() <= opts.eps * (m[[p, p]] * m[[q, q]]).abs().sqrt() {
+                    continue;
+                }
+                converged = false;
+
+                let t = jacobi_tangent(m[[p, p]], m[[q, q]], apq);
+                let c = 1. / t.hypot(1.);
+                let s = t * c;
+
+                rotate_columns(m.view_mut(), p, q, c, s);
+                rotate_columns(m.view_mut().reversed_axes(), p, q, c, s);
+                m[[p, q]] = 0.;
+                m[[q, p]] = 0.;
+                if opts.accumulate_sim_transforms {
+                    rotate_columns(u.view_mut(), p, q, c, s);
+                }
+            }
+        }
+        sweeps += 1;
+    }
+
+    let off_norm = scaled_off_norm(m.view());
+    (JacobiInfo { sweeps, off_norm }, converged)
+}
+
+// One-sided (Hestenes) Jacobi on the columns of an m by n matrix with m >= n:
+// rotates A V until its columns are orthogonal relative to their norms.
+// Outputs (A V, V, info, converged)
+fn one_sided_jacobi(m: MatrixView, opts: &QROptions) -> (Matrix, Matrix, JacobiInfo, bool) {
+    let n = m.shape()[1];
+    let mut w = m.into_owned();
+    let mut v = Matrix::eye(n);
+    let mut sweeps = 0;
+    let mut converged = false;
+
+    while !converged && sweeps < opts.iterations {
+        converged = true;
+        for p in 0..n {
+            for q in p + 1..n {
+                let gamma = w.column(p).dot(&w.column(q));
+                let alpha = w.column(p).dot(&w.column(p));
+                let beta = w.column(q).dot(&w.column(q));
+                if gamma == 0. || gamma.abs() <= opts.eps * (alpha * beta).sqrt() {
+                    continue;
+                }
+                converged = false;
+
+                let t = jacobi_tangent(alpha, beta, gamma);
+                let c = 1. / t.hypot(1.);
+                let s = t * c;
+                rotate_columns(w.view_mut(), p, q, c, s);
+                rotate_columns(v.view_mut(), p, q, c, s);
+            }
+        }
+        sweeps += 1;
+    }
+
+    let off_norm = scaled_off_norm(w.t().dot(&w).view());
+    (w, v, JacobiInfo { sweeps, off_norm }, converged)
+}
+
+// Outputs (U, S, V^T, info, converged) in the format of `svd`
+pub fn jacobi_svd(m: MatrixView, opts: &QROptions) -> (Matrix, Vector, Matrix, JacobiInfo, bool) {
+    let (rows, cols) = (m.shape()[0], m.shape()[1]);
+    if rows < cols {
+        let (u, z, vt, info, converged) = jacobi_svd(m.t(), opts);
+        return (
+            vt.t().into_owned(),
+            z.slice(s![0..rows]).into_owned(),
+            u.t().into_owned(),
+            info,
+            converged,
+        );
+    }
+
+    let (w, v, info, converged) = one_sided_jacobi(m, opts);
+    let norms: Vec<f64> = w
+        .gencolumns()
+        .into_iter()
+        .map(|x| x.dot(&x).sqrt())
+        .collect();
+    let mut perm: Vec<usize> = (0..cols).collect();
+    perm.sort_by(|i, j| norms[*j].partial_cmp(&norms[*i]).unwrap_or(Ordering::Equal));
+
+    let mut z = Vector::zeros(rows);
+    let mut us = Vec::new();
+    for (k, &i) in perm.iter().enumerate() {
+        z[k] = norms[i];
+        if norms[i] > 0. {
+            us.push(w.column(i).to_owned() / norms[i]);
+        }
+    }
+    us.extend(
+        Matrix::eye(rows)
+            .gencolumns()
+            .into_iter()
+            .map(|x| x.into_owned()),
+    );
+    let u = stack_owned(Axis(1), &orthonormal_basis(us.as_slice(), rows, opts.eps));
+
+    let vt = v.select(Axis(1), &perm).reversed_axes();
+    (u, z, vt, info, converged)
+}
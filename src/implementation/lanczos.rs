@@ -0,0 +1,276 @@
+use crate::implementation::common::*;
+use crate::implementation::hessenberg::hessenberg_form;
+use crate::implementation::qr_symmetric::qr_algorithm_symmetric;
+use crate::*;
+
+use ndarray::{s, Array, Axis};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+// The projected problem is solved to full accuracy, since the residual estimates
+// are read off the last row of its eigenvectors
+const PROJECTED_OPTS: QROptions = QROptions {
+    eps: f64::EPSILON,
+    ..SYMMETRIC_OPTS
+};
+
+// Power steps for the estimate of |A| when the Lanczos algorithm runs on another operator
+const NORM_STEPS: usize = 10;
+
+// Basis size for k wanted eigenpairs of an n by n operator
+#[inline]
+fn basis_size(n: usize, k: usize) -> usize {
+    n.min((2 * k).max(k + 20))
+}
+
+// Orthogonalizes w against the orthonormal vectors vs, twice for stability
+#[inline]
+fn reorthogonalize<'a>(w: &mut Vector, vs: impl Iterator<Item = &'a Vector> + Clone) {
+    for _ in 0..2 {
+        for v in vs.clone() {
+            let c = v.dot(w);
+            w.scaled_add(-c, v);
+        }
+    }
+}
+
+// Random unit vector orthogonal to the orthonormal vectors vs
+fn random_orthogonal(n: usize, vs: &[Vector]) -> Vector {
+    loop {
+        let mut w: Vector = Array::random(n, Uniform::new(-1., 1.));
+        reorthogonalize(&mut w, vs.iter());
+        let nw = norm(w.view());
+        if nw > f64::EPSILON.sqrt() {
+            return w / nw;
+        }
+    }
+}
+
+// Eigenpairs of the small symmetric matrix t with eigenvalues in descending order
+pub fn projected_eigen(t: MatrixView) -> (Vector, Matrix) {
+    let mut t = t.into_owned();
+    let mut s = Matrix::eye(t.nrows());
+    hessenberg_form(t.view_mut(), s.view_mut(), &PROJECTED_OPTS);
+    qr_algorithm_symmetric(t.view_mut(), s.view_mut(), &PROJECTED_OPTS);
+
+    let mut theta = t.diag().into_owned();
+    sort_diagonal_values(theta.view_mut(), s.view_mut());
+    (theta, s)
+}
+
+// Ritz vectors of the projected problem of size j + 1 whose residual estimate beta |s_j| is below tol
+fn converged_ritz_vectors(v: &[Vector], t: MatrixView, beta: f64, tol: f64) -> Vec<Vector> {
+    let j = t.nrows() - 1;
+    let (_, s) = projected_eigen(t);
+    s.gencolumns()
+        .into_iter()
+        .filter(|c| beta * c[j].abs() <= tol)
+        .map(|c| {
+            let mut y = Vector::zeros(v[0].len());
+            for (vi, ci) in v.iter().zip(c.iter()) {
+                y.scaled_add(*ci, vi);
+            }
+            let ny = norm(y.view());
+            y / ny
+        })
+        .collect()
+}
+
+// Advances the omega-recurrence (Simon) that estimates the inner products omega_ik = v_i . v_k of the Lanczos basis
+// from the projected matrix alone: w = A v_j - sum_i T_ij v_i and the symmetry of A give
+// beta omega_{j+1,k} = sum_i T_ik omega_ji - sum_i T_ij omega_ki for k < j, plus a rounding term of order eps |A|.
+// Stores the estimates for the new vector w / beta and returns the largest one
+fn omega_step(mut omega: MatrixViewMut, t: MatrixView, j: usize, beta: f64, scale: f64) -> f64 {
+    let mut lost: f64 = 0.;
+    for k in 0..j {
+        let x = t.slice(s![..=j, k]).dot(&omega.slice(s![j, ..=j]))
+            - t.slice(s![..=j, j]).dot(&omega.slice(s![k, ..=j]));
+        let x = (x + x.signum() * f64::EPSILON * scale) / beta;
+        omega[[j + 1, k]] = x;
+        omega[[k, j + 1]] = x;
+        lost = lost.max(x.abs());
+    }
+
+    omega[[j + 1, j]] = f64::EPSILON;
+    omega[[j, j + 1]] = f64::EPSILON;
+    omega[[j + 1, j + 1]] = 1.;
+    lost
+}
+
+// Ritz values in descending order of magnitude, with the columns of s permuted alike
+fn order_by_magnitude(theta: Vector, s: Matrix) -> (Vector, Matrix) {
+    let mut order: Vec<usize> = (0..theta.len()).collect();
+    order.sort_by(|i, j| theta[*j].abs().partial_cmp(&theta[*i].abs()).unwrap());
+    (theta.select(Axis(0), &order), s.select(Axis(1), &order))
+}
+
+// Thick-restart Lanczos algorithm (Wu and Simon) for the k largest eigenpairs of the n by n symmetric operator apply,
+// or the k largest in magnitude if by_magnitude is set.
+// After each restart the basis starts with the kept Ritz vectors, so the projected matrix is diagonal
+// with an arrow coupling them to the next basis vector, followed by the usual tridiagonal part.
+// Stops once the residual estimates of the k wanted Ritz pairs are below eps |A|, with |A| estimated by the largest |A v|,
+// or below eps |theta| for each Ritz value theta if by_magnitude is set (for shift-invert, where the small ones are wanted too).
+// Outputs (values, vectors, restarts, estimate of |A|), values in descending order (of magnitude),
+// or None after opts.iterations restarts
+pub fn thick_restart_lanczos<F>(
+    apply: F,
+    n: usize,
+    k: usize,
+    by_magnitude: bool,
+    reorth: Reorthogonalization,
+    opts: &QROptions,
+) -> Option<(Vector, Matrix, usize, f64)>
+where
+    F: Fn(VectorView) -> Vector,
+{
+    let m = basis_size(n, k);
+    let mut v: Vec<Vector> = vec![random_orthogonal(n, &[])];
+    let mut t = Matrix::zeros((m, m));
+    let mut l = 0;
+    let mut scale: f64 = 0.;
+
+    // estimates of the inner products of the basis vectors, only tracked for selective reorthogonalization
+    let mut omega = Matrix::eye(m + 1);
+
+    for restart in 0..=opts.iterations {
+        let mut f = Vector::zeros(n);
+        let mut beta = 0.;
+        let mut again = false;
+
+        for j in l..m {
+            let mut w = apply(v[j].view());
+            scale = scale.max(norm(w.view()));
+
+            let alpha = v[j].dot(&w);
+            t[[j, j]] = alpha;
+            w.scaled_add(-alpha, &v[j]);
+            if j == l {
+                for i in 0..l {
+                    w.scaled_add(-t[[i, l]], &v[i]);
+                }
+            } else {
+                w.scaled_add(-t[[j - 1, j]], &v[j - 1]);
+            }
+
+            match reorth {
+                Reorthogonalization::Full => reorthogonalize(&mut w, v.iter()),
+                // Parlett and Scott: the basis loses orthogonality only in the directions of converged Ritz vectors,
+                // so once the estimated loss reaches sqrt(eps) the new vector is orthogonalized against them
+                // and against the kept vectors, falling back to the whole basis if none has converged yet
+                Reorthogonalization::Selective => {
+                    let beta = norm(w.view());
+                    let lost = if beta > f64::EPSILON * scale {
+                        omega_step(omega.view_mut(), t.view(), j, beta, scale)
+                    } else {
+                        0.
+                    };
+                    // the next vector is orthogonalized as well, since the recurrence carries the lost components over
+                    if again || lost > f64::EPSILON.sqrt() {
+                        again = !again;
+                        let tol = f64::EPSILON.sqrt() * scale;
+                        let ritz = converged_ritz_vectors(&v, t.slice(s![..=j, ..=j]), beta, tol);
+                        if ritz.is_empty() {
+                            reorthogonalize(&mut w, v.iter());
+                        } else {
+                            reorthogonalize(&mut w, v[..l].iter().chain(ritz.iter()));
+                        }
+                        omega.slice_mut(s![j + 1, ..=j]).fill(f64::EPSILON);
+                        omega.slice_mut(s![..=j, j + 1]).fill(f64::EPSILON);
+                    }
+                }
+            }
+
+            beta = norm(w.view());
+            if j + 1 == m {
+                f = w;
+                break;
+            }
+
+            // the Krylov space is invariant, continue with a new random direction
+            if beta <= f64::EPSILON * scale {
+                v.push(random_orthogonal(n, &v));
+                omega.slice_mut(s![j + 1, ..=j]).fill(f64::EPSILON);
+                omega.slice_mut(s![..=j, j + 1]).fill(f64::EPSILON);
+                continue;
+            }
+
+            t[[j, j + 1]] = beta;
+            t[[j + 1, j]] = beta;
+            v.push(w / beta);
+        }
+
+        let (theta, s) = projected_eigen(t.view());
+        let (theta, s) = if by_magnitude {
+            order_by_magnitude(theta, s)
+        } else {
+            (theta, s)
+        };
+        let converged = (0..k).all(|i| {
+            let tol = if by_magnitude { theta[i].abs() } else { scale };
+            beta * s[[m - 1, i]].abs() <= opts.eps * tol
+        });
+        if converged || m == n {
+            let basis = stack_owned(Axis(1), &v);
+            let x = basis.dot(&s.slice(s![.., ..k]));
+            return Some((theta.slice(s![..k]).into_owned(), x, restart, scale));
+        }
+
+        // keep the k wanted Ritz vectors and half of the rest
+        l = k + (m - k) / 2;
+        let basis = stack_owned(Axis(1), &v);
+        let y = basis.dot(&s.slice(s![.., ..l]));
+        v = y.gencolumns().into_iter().map(|c| c.into_owned()).collect();
+
+        let invariant = beta <= f64::EPSILON * scale;
+        t.fill(0.);
+        for i in 0..l {
+            t[[i, i]] = theta[i];
+            if !invariant {
+                t[[i, l]] = beta * s[[m - 1, i]];
+                t[[l, i]] = t[[i, l]];
+            }
+        }
+
+        if invariant {
+            v.push(random_orthogonal(n, &v));
+        } else {
+            v.push(f / beta);
+        }
+
+        // the kept Ritz vectors and the residual inherit the level of orthogonality of the old basis
+        // (bounded in absolute value, since the signs of the estimates are arbitrary)
+        let sl = s.slice(s![.., ..l]).mapv(f64::abs);
+        let bound = omega.mapv(f64::abs);
+        let mut kept = Matrix::from_elem((m + 1, m + 1), f64::EPSILON);
+        kept.slice_mut(s![..l, ..l])
+            .assign(&sl.t().dot(&bound.slice(s![..m, ..m])).dot(&sl));
+        if !invariant {
+            let last = sl.t().dot(&bound.slice(s![m, ..m]));
+            kept.slice_mut(s![l, ..l]).assign(&last);
+            kept.slice_mut(s![..l, l]).assign(&last);
+        }
+        kept.diag_mut().fill(1.);
+        omega = kept;
+    }
+
+    None
+}
+
+// Lower estimate of |A| for the n by n operator apply by a few steps of power iteration from a random vector
+pub fn norm_estimate<F>(apply: F, n: usize) -> f64
+where
+    F: Fn(VectorView) -> Vector,
+{
+    let mut x = random_orthogonal(n, &[]);
+    let mut estimate: f64 = 0.;
+    for _ in 0..NORM_STEPS {
+        let y = apply(x.view());
+        let ny = norm(y.view());
+        estimate = estimate.max(ny);
+        if ny == 0. || !ny.is_finite() {
+            break;
+        }
+        x = y / ny;
+    }
+    estimate
+}
@@ -0,0 +1,66 @@
+use crate::implementation::householder::*;
+use crate::implementation::svd::svd;
+use crate::implementation::triangular::solve_upper_triangular;
+use crate::*;
+
+use ndarray::s;
+use std::cmp::min;
+
+pub fn householder_qr(mut a: MatrixViewMut) -> Vec<Vector> {
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    let mut refls = Vec::with_capacity(n);
+    for k in 0..min(m, n) {
+        let v = householder_vec(a.slice(s![k..m, k]));
+        householder_refl_left(v.view(), a.slice_mut(s![k..m, k..n]));
+        a.slice_mut(s![k + 1..m, k]).fill(0.);
+        refls.push(v);
+    }
+    refls
+}
+
+pub fn apply_householder_qt(refls: &[Vector], mut b: MatrixViewMut) {
+    let m = b.shape()[0];
+    for (k, v) in refls.iter().enumerate() {
+        householder_refl_left(v.view(), b.slice_mut(s![k..m, ..]));
+    }
+}
+
+pub fn least_squares_qr(a: MatrixView, b: MatrixView, tol: f64) -> Option<Matrix> {
+    let n = a.shape()[1];
+    let mut r = a.into_owned();
+    let refls = householder_qr(r.view_mut());
+
+    let d: Vec<f64> = r.diag().iter().map(|x| x.abs()).collect();
+    let dmax = d.iter().cloned().fold(0., f64::max);
+    if d.len() < n || d.iter().any(|x| x.is_nan() || *x <= tol * dmax) {
+        return None;
+    }
+
+    let mut qtb = b.into_owned();
+    apply_householder_qt(&refls, qtb.view_mut());
+    let mut x = qtb.slice(s![0..n, ..]).into_owned();
+    solve_upper_triangular(r.slice(s![0..n, 0..n]), x.view_mut());
+    Some(x)
+}
+
+pub fn pseudoinverse(a: MatrixView, rcond: f64, opts: &QROptions) -> (Matrix, usize) {
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    let (u, z, vt) = svd(a, opts);
+
+    let rank = (0..min(m, n)).take_while(|i| z[*i] > rcond * z[0]).count();
+    let mut v = vt.slice(s![0..rank, ..]).t().into_owned();
+    for i in 0..rank {
+        v.column_mut(i).mapv_inplace(|x| x / z[i]);
+    }
+    (v.dot(&u.slice(s![.., 0..rank]).t()), rank)
+}
+
+pub fn least_squares_svd(
+    a: MatrixView,
+    b: MatrixView,
+    rcond: f64,
+    opts: &QROptions,
+) -> (Matrix, usize) {
+    let (p, rank) = pseudoinverse(a, rcond, opts);
+    (p.dot(&b), rank)
+}
@@ -0,0 +1,55 @@
+use crate::implementation::expm::norm_1;
+use crate::implementation::lu::{lu_decomposition, lu_solve};
+use crate::implementation::sqrtm::sqrtm_quasi_triangular;
+use crate::*;
+
+// Gauss-Legendre nodes and weights on [-1, 1], positive half
+const NODES: [f64; 4] = [
+    0.183_434_642_495_649_8,
+    0.525_532_409_916_329,
+    0.796_666_477_413_626_7,
+    0.960_289_856_497_536_3,
+];
+const WEIGHTS: [f64; 4] = [
+    0.362_683_783_378_362,
+    0.313_706_645_877_887_3,
+    0.222_381_034_453_374_5,
+    0.101_228_536_290_376_3,
+];
+
+// Degree 8 Pade approximant to log(I + X) is accurate to unit roundoff for |X|_1 below this
+const THETA_8: f64 = 0.25;
+const MAX_SQRTS: usize = 64;
+
+fn log_pade(x: MatrixView) -> Matrix {
+    let n = x.shape()[0];
+    let mut r = Matrix::zeros((n, n));
+
+    for (&node, &weight) in NODES.iter().zip(WEIGHTS.iter()) {
+        for sgn in [-1., 1.] {
+            let (xj, wj) = ((1. + sgn * node) / 2., weight / 2.);
+            let mut lu = Matrix::eye(n) + xj * &x;
+            let (perm, _) = lu_decomposition(lu.view_mut());
+            r.scaled_add(wj, &lu_solve(lu.view(), &perm, x));
+        }
+    }
+
+    r
+}
+
+pub fn logm_quasi_triangular(t: MatrixView) -> Option<Matrix> {
+    let n = t.shape()[0];
+    let mut r = t.into_owned();
+    let mut scale = 1.;
+
+    for _ in 0..MAX_SQRTS {
+        let x = &r - &Matrix::eye(n);
+        if norm_1(x.view()) <= THETA_8 {
+            return Some(scale * log_pade(x.view()));
+        }
+        r = sqrtm_quasi_triangular(r.view());
+        scale *= 2.;
+    }
+
+    None
+}
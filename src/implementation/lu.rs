@@ -0,0 +1,57 @@
+use crate::implementation::triangular::*;
+use crate::*;
+
+pub fn lu_decomposition(mut m: MatrixViewMut) -> (Vec<usize>, f64) {
+    let n = m.shape()[0];
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut sign = 1.;
+
+    for k in 0..n {
+        let p = (k..n).fold(k, |p, i| {
+            if m[[i, k]].abs() > m[[p, k]].abs() {
+                i
+            } else {
+                p
+            }
+        });
+        if p != k {
+            for j in 0..n {
+                m.swap([k, j], [p, j]);
+            }
+            perm.swap(k, p);
+            sign = -sign;
+        }
+
+        if m[[k, k]] == 0. {
+            continue;
+        }
+
+        for i in k + 1..n {
+            let l = m[[i, k]] / m[[k, k]];
+            m[[i, k]] = l;
+            for j in k + 1..n {
+                m[[i, j]] -= l * m[[k, j]];
+            }
+        }
+    }
+
+    (perm, sign)
+}
+
+pub fn lu_singular(lu: MatrixView) -> bool {
+    let n = lu.shape()[0];
+    let amax = lu.iter().fold(0., |acc: f64, x| acc.max(x.abs()));
+    let tol = f64::EPSILON * n as f64 * amax;
+    lu.diag().iter().any(|x| x.abs() <= tol)
+}
+
+pub fn lu_solve(lu: MatrixView, perm: &[usize], b: MatrixView) -> Matrix {
+    let mut x = Matrix::zeros((b.shape()[0], b.shape()[1]));
+    for (i, &p) in perm.iter().enumerate() {
+        x.row_mut(i).assign(&b.row(p));
+    }
+
+    solve_unit_lower_triangular(lu, x.view_mut());
+    solve_upper_triangular(lu, x.view_mut());
+    x
+}
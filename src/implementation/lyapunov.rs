@@ -0,0 +1,146 @@
+use crate::implementation::common::*;
+use crate::implementation::householder::*;
+use crate::implementation::jacobi::jacobi_tangent;
+use crate::implementation::least_squares::householder_qr;
+use crate::implementation::sylvester::*;
+use crate::*;
+
+use ndarray::{array, concatenate, s, Axis};
+
+// P T^T P for the reversal permutation P: upper quasi-triangular with the same blocks as T, in reverse order
+pub fn flipped_transpose(t: MatrixView) -> Matrix {
+    t.t().slice(s![..;-1, ..;-1]).into_owned()
+}
+
+pub fn lyapunov_continuous(t: MatrixView, u: MatrixView, q: MatrixView) -> Matrix {
+    let tf = flipped_transpose(t);
+    let uf = u.slice(s![.., ..;-1]);
+    bartels_stewart(t, u, tf.view(), uf, (-&q).view(), 1.)
+}
+
+pub fn lyapunov_discrete(t: MatrixView, u: MatrixView, q: MatrixView) -> Matrix {
+    let tf = flipped_transpose(t);
+    let mut z = -u.t().dot(&q).dot(&u).slice(s![.., ..;-1]).into_owned();
+    stein_quasi_triangular(t, tf.view(), z.view_mut());
+    u.dot(&z.slice(s![.., ..;-1])).dot(&u.t())
+}
+
+// Y = F F^T for a 1 by 1 or 2 by 2 positive semidefinite Y, with F = V D^1/2 from its eigendecomposition Y = V D V^T.
+// Eigenvalues at the rounding level of Y are dropped, so that F has full column rank and F^T F = D.
+// Outputs (F, D)
+fn semidefinite_block_factor(y: MatrixView) -> (Matrix, Vector) {
+    let (v, d) = if y.shape()[0] == 2 && y[[0, 1]] != 0. {
+        let t = jacobi_tangent(y[[0, 0]], y[[1, 1]], y[[0, 1]]);
+        let c = 1. / t.hypot(1.);
+        let s = t * c;
+        let d = [y[[0, 0]] - t * y[[0, 1]], y[[1, 1]] + t * y[[0, 1]]];
+        (array![[c, s], [-s, c]], d.to_vec())
+    } else {
+        (Matrix::eye(y.shape()[0]), y.diag().to_vec())
+    };
+
+    let dmax = d.iter().cloned().fold(0., f64::max);
+    let kept: Vec<usize> = (0..d.len())
+        .filter(|i| d[*i] > f64::EPSILON * dmax)
+        .collect();
+    let mut f = v.select(Axis(1), &kept);
+    for (mut col, i) in f.gencolumns_mut().into_iter().zip(kept.iter()) {
+        col *= d[*i].sqrt();
+    }
+    (f, kept.iter().map(|i| d[*i]).collect())
+}
+
+pub fn hammarling(t: MatrixView, mut b: Matrix, discrete: bool) -> Option<Matrix> {
+    let n = t.shape()[0];
+    let mut r = Matrix::zeros((n, n));
+
+    for &(j, q) in schur_blocks(t).iter().rev() {
+        let t22 = t.slice(s![j..j + q, j..j + q]);
+        let stable =
+            extract_eigenvalues(t22).iter().all(
+                |l| {
+                    if discrete {
+                        l.norm() < 1.
+                    } else {
+                        l.re < 0.
+                    }
+                },
+            );
+        if !stable {
+            return None;
+        }
+
+        let b2 = b.slice(s![j..j + q, ..]).into_owned();
+        let mut y22 = -b2.dot(&b2.t());
+        if discrete {
+            stein_quasi_triangular(t22, t22.t(), y22.view_mut());
+        } else {
+            sylvester_quasi_triangular(t22, t22.t(), y22.view_mut(), 1.);
+        }
+        // R22 = [F 0]; F may have fewer columns than R22 if Y22 is singular
+        let (f, d) = semidefinite_block_factor(y22.view());
+        let rank = d.len();
+        r.slice_mut(s![j..j + q, j..j + rank]).assign(&f);
+
+        if j == 0 || rank == 0 {
+            continue;
+        }
+
+        let (t11, t12) = (t.slice(s![0..j, 0..j]), t.slice(s![0..j, j..j + q]));
+        let b1 = b.slice(s![0..j, ..]).into_owned();
+        let mut y12 = -b1.dot(&b2.t());
+        if discrete {
+            y12 -= &t12.dot(&y22).dot(&t22.t());
+            stein_quasi_triangular(t11, t22.t(), y12.view_mut());
+        } else {
+            y12 -= &t12.dot(&y22);
+            sylvester_quasi_triangular(t11, t22.t(), y12.view_mut(), 1.);
+        }
+
+        // R12 = Y12 (F^T)^+ = Y12 F D^-1, exact since the rows of Y12 lie in the range of Y22
+        let mut fp = f.t().into_owned();
+        for (mut row, di) in fp.genrows_mut().into_iter().zip(d.iter()) {
+            row /= *di;
+        }
+        let r12 = y12.dot(&fp.t());
+        r.slice_mut(s![0..j, j..j + rank]).assign(&r12);
+
+        let b1 = if discrete {
+            // B1 <- [T11 R12 + T12 F, B1] N, where N spans the null space of F^+ [T22 F, B2]
+            let w = fp.dot(&concatenate(Axis(1), &[t22.dot(&f).view(), b2.view()]).unwrap());
+            let mut wt = w.t().into_owned();
+            let k = wt.shape()[0];
+            let mut qm = Matrix::eye(k);
+            for c in 0..rank {
+                let v = householder_vec(wt.slice(s![c..k, c]));
+                householder_refl_left(v.view(), wt.slice_mut(s![c..k, c..rank]));
+                householder_refl_right(v.view(), qm.slice_mut(s![.., c..k]));
+            }
+
+            let y = t11.dot(&r12) + t12.dot(&f);
+            concatenate(Axis(1), &[y.view(), b1.view()])
+                .unwrap()
+                .dot(&qm.slice(s![.., rank..k]))
+        } else {
+            // B1 <- B1 - R12 F^+ B2
+            b1 - r12.dot(&fp.dot(&b2))
+        };
+        b.slice_mut(s![0..j, ..]).assign(&b1);
+    }
+
+    Some(r)
+}
+
+// X = U R R^T U^T = L L^T with L lower triangular, from the QR decomposition of (U R)^T
+pub fn lower_factor(u: MatrixView, r: MatrixView) -> Matrix {
+    let mut f = u.dot(&r).t().into_owned();
+    householder_qr(f.view_mut());
+    let mut l = f.t().into_owned();
+    for mut col in l.gencolumns_mut() {
+        let i = col.iter().position(|x| *x != 0.).unwrap_or(0);
+        if col[i] < 0. {
+            col.mapv_inplace(|x| -x);
+        }
+    }
+    l
+}
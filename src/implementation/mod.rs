@@ -1,10 +1,34 @@
+pub mod bisection;
 pub mod blocks;
 pub mod checks;
+pub mod cholesky;
 pub mod common;
+pub mod divide_conquer;
+pub mod dqds;
+pub mod expm;
 pub mod francis;
 pub mod givens;
 pub mod hessenberg;
 pub mod householder;
+pub mod jacobi;
+pub mod lanczos;
+pub mod least_squares;
+pub mod logm;
+pub mod lu;
+pub mod lyapunov;
+pub mod mrrr;
+pub mod polynomial;
+pub mod power;
 pub mod qr_basic;
 pub mod qr_symmetric;
+pub mod quadrature;
+pub mod qz;
+pub mod reorder;
+pub mod riccati;
+pub mod schur_parlett;
+pub mod signm;
+pub mod sqrtm;
 pub mod svd;
+pub mod sylvester;
+pub mod symmetric_definite;
+pub mod triangular;
@@ -0,0 +1,407 @@
+use crate::implementation::bisection::{bisection, inverse_iteration, tridiagonal_norm};
+use crate::implementation::dqds::dqds;
+use crate::*;
+
+use ndarray::{s, Array};
+use ndarray_rand::rand::{rngs::StdRng, SeedableRng};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use std::cmp::Ordering;
+
+// eigenvalues with a smaller relative gap form a cluster and get a new representation
+const GAP_TOL: f64 = 1e-3;
+// each level moves the shift closer to a cluster by about the accepted element growth,
+// enough to separate clusters as tight as the rounding level of the root representation
+const MAX_DEPTH: usize = 24;
+const MAX_GROWTH: f64 = 64.;
+const SHIFT_ATTEMPTS: usize = 24;
+const BISECTION_ITERATIONS: usize = 256;
+// the eigenvalues of a representation are refined to full relative accuracy, since its relative gaps decide the clusters
+const REFINE_TOL: f64 = 4. * f64::EPSILON;
+const RAYLEIGH_ITERATIONS: usize = 10;
+// the root representation is perturbed by this many ulps, so that eigenvalues of nearly decoupled blocks
+// that agree to the rounding level (glued matrices) get relative gaps the child representations can resolve
+const ROOT_PERTURBATION: f64 = 8.;
+
+#[inline]
+fn nonzero(x: f64) -> f64 {
+    if x == 0. {
+        f64::MIN_POSITIVE
+    } else {
+        x
+    }
+}
+
+#[inline]
+fn relative_gap(a: f64, b: f64) -> f64 {
+    let scale = a.abs().max(b.abs());
+    if scale == 0. {
+        0.
+    } else {
+        (b - a).abs() / scale
+    }
+}
+
+// Last index of the cluster of close eigenvalues starting at i
+fn cluster_end(lambda: &[f64], i: usize) -> usize {
+    let mut j = i;
+    while j + 1 < lambda.len() && relative_gap(lambda[j], lambda[j + 1]) < GAP_TOL {
+        j += 1;
+    }
+    j
+}
+
+// Stationary qd transform L+ D+ L+^T = L D L^T - tau I; outputs (D+, L+, number of negative pivots)
+fn stationary_qd(d: &[f64], l: &[f64], tau: f64) -> (Vec<f64>, Vec<f64>, usize) {
+    let n = d.len();
+    let (mut dp, mut lp) = (vec![0.; n], vec![0.; n - 1]);
+    let mut s = -tau;
+    let mut count = 0;
+
+    for i in 0..n {
+        dp[i] = nonzero(d[i] + s);
+        if dp[i] < 0. {
+            count += 1;
+        }
+        if i + 1 < n {
+            lp[i] = d[i] * l[i] / dp[i];
+            s = lp[i] * l[i] * s - tau;
+        }
+    }
+
+    (dp, lp, count)
+}
+
+// Number of eigenvalues of L D L^T less than tau, from the signs of D+ in `stationary_qd`
+fn negative_count(d: &[f64], l: &[f64], tau: f64) -> usize {
+    let mut s = -tau;
+    let mut count = 0;
+
+    for i in 0..d.len() {
+        let dp = nonzero(d[i] + s);
+        if dp < 0. {
+            count += 1;
+        }
+        if i + 1 < d.len() {
+            s = d[i] * l[i] / dp * l[i] * s - tau;
+        }
+    }
+
+    count
+}
+
+// k-th smallest eigenvalue of L D L^T to relative accuracy REFINE_TOL, starting from the bracket [lo, hi]
+fn representation_bisection(d: &[f64], l: &[f64], k: usize, mut lo: f64, mut hi: f64) -> f64 {
+    let mut width = (hi - lo).max(f64::MIN_POSITIVE);
+    while negative_count(d, l, lo) > k {
+        lo -= width;
+        width *= 2.;
+    }
+    while negative_count(d, l, hi) <= k {
+        hi += width;
+        width *= 2.;
+    }
+
+    for _ in 0..BISECTION_ITERATIONS {
+        if hi - lo <= REFINE_TOL * lo.abs().max(hi.abs()) {
+            break;
+        }
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if negative_count(d, l, mid) > k {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+// Eigenvector of L D L^T for the eigenvalue l from the twisted factorization N_r D_r N_r^T = L D L^T - l I
+// with the twist index r minimizing |gamma_r|, refined by the Rayleigh quotient correction l += gamma_r / |z|^2
+fn twisted_vector(d: &[f64], l: &[f64], mut lambda: f64) -> Option<Vector> {
+    let n = d.len();
+    let mut z = Vector::zeros(n);
+
+    for _ in 0..RAYLEIGH_ITERATIONS {
+        let (_, lp, _) = stationary_qd(d, l, lambda);
+        let mut s = vec![-lambda; n];
+        for i in 0..n - 1 {
+            let dp = nonzero(d[i] + s[i]);
+            s[i + 1] = d[i] * l[i] / dp * l[i] * s[i] - lambda;
+        }
+
+        // progressive qd transform U- D- U-^T = L D L^T - l I from the bottom
+        let (mut p, mut um) = (vec![0.; n], vec![0.; n - 1]);
+        p[n - 1] = d[n - 1] - lambda;
+        for i in (0..n - 1).rev() {
+            let dm = nonzero(d[i] * l[i] * l[i] + p[i + 1]);
+            let t = d[i] / dm;
+            um[i] = l[i] * t;
+            p[i] = p[i + 1] * t - lambda;
+        }
+
+        let gamma: Vec<f64> = (0..n).map(|k| s[k] + p[k] + lambda).collect();
+        let r = (0..n)
+            .min_by(|a, b| {
+                gamma[*a]
+                    .abs()
+                    .partial_cmp(&gamma[*b].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+
+        z[r] = 1.;
+        for i in (0..r).rev() {
+            z[i] = -lp[i] * z[i + 1];
+        }
+        for i in r..n - 1 {
+            z[i + 1] = -um[i] * z[i];
+        }
+
+        let norm2 = z.dot(&z);
+        if !norm2.is_finite() {
+            return None;
+        }
+        let correction = gamma[r] / norm2;
+        if correction.abs() <= 4. * f64::EPSILON * lambda.abs() || correction == 0. {
+            break;
+        }
+        lambda += correction;
+    }
+
+    let norm = z.dot(&z).sqrt();
+    Some(z / norm)
+}
+
+// New representation L+ D+ L+^T = L D L^T - tau I for the cluster lambda[i..=j], shifted just outside either end
+// so that its relative gaps become large. The shift moves away from the cluster until the element growth of D+ is small;
+// otherwise the least growth below 1 / sqrt(eps) is accepted. Outputs (D+, L+, tau)
+fn cluster_representation(
+    d: &[f64],
+    l: &[f64],
+    lambda: &[f64],
+    i: usize,
+    j: usize,
+) -> Option<(Vec<f64>, Vec<f64>, f64)> {
+    let scale = d.iter().fold(0., |acc: f64, x| acc.max(x.abs()));
+    let inner = (i..j)
+        .map(|m| lambda[m + 1] - lambda[m])
+        .fold(f64::INFINITY, f64::min);
+    let mut delta = (0.25 * inner).max(4. * f64::EPSILON * lambda[i].abs().max(lambda[j].abs()));
+    // stay closer to the cluster than to its neighbours
+    let left = if i > 0 {
+        0.5 * (lambda[i] - lambda[i - 1])
+    } else {
+        f64::INFINITY
+    };
+    let right = if j + 1 < lambda.len() {
+        0.5 * (lambda[j + 1] - lambda[j])
+    } else {
+        f64::INFINITY
+    };
+    let mut best: Option<(Vec<f64>, Vec<f64>, f64)> = None;
+    let mut best_growth = f64::INFINITY;
+
+    for _ in 0..SHIFT_ATTEMPTS {
+        for (tau, limit) in [(lambda[i] - delta, left), (lambda[j] + delta, right)].iter() {
+            if delta > *limit {
+                continue;
+            }
+            let (dp, lp, _) = stationary_qd(d, l, *tau);
+            let growth = dp.iter().fold(0., |acc: f64, x| acc.max(x.abs())) / (scale + tau.abs());
+            if !growth.is_finite() || lp.iter().any(|x| !x.is_finite()) {
+                continue;
+            }
+            if growth <= MAX_GROWTH {
+                return Some((dp, lp, *tau));
+            }
+            if growth < best_growth {
+                best_growth = growth;
+                best = Some((dp, lp, *tau));
+            }
+        }
+        delta *= 4.;
+    }
+
+    if best_growth < 1. / f64::EPSILON.sqrt() {
+        best
+    } else {
+        None
+    }
+}
+
+// Eigenvectors of L D L^T for its eigenvalues lambda (ascending, with indices idx in the full spectrum and relative accuracy rtol).
+// Vectors computed from the same representation are orthogonal as long as their eigenvalues have large relative gaps in it.
+// None for the eigenvectors of clusters that could not be given a relatively robust representation
+fn representation_vectors(
+    d: &[f64],
+    l: &[f64],
+    lambda: &[f64],
+    idx: &[usize],
+    rtol: f64,
+    depth: usize,
+) -> Vec<Option<Vector>> {
+    let k = lambda.len();
+    let mut out = vec![None; k];
+
+    let mut i = 0;
+    while i < k {
+        let j = cluster_end(lambda, i);
+
+        if i == j {
+            out[i] = twisted_vector(d, l, lambda[i]);
+        } else if depth < MAX_DEPTH && lambda[j] > lambda[i] {
+            if let Some((dp, lp, tau)) = cluster_representation(d, l, lambda, i, j) {
+                // the accuracy of lambda brackets the shifted eigenvalue
+                let shifted: Vec<f64> = (i..=j)
+                    .map(|m| {
+                        let x = lambda[m] - tau;
+                        let width = 2. * rtol * lambda[m].abs();
+                        representation_bisection(&dp, &lp, idx[m], x - width, x + width)
+                    })
+                    .collect();
+                let vectors =
+                    representation_vectors(&dp, &lp, &shifted, &idx[i..=j], REFINE_TOL, depth + 1);
+                for (m, v) in vectors.into_iter().enumerate() {
+                    out[i + m] = v;
+                }
+            }
+        }
+
+        i = j + 1;
+    }
+
+    out
+}
+
+// Eigenvalues (ascending) and eigenvectors of an unreduced block from the representation tree,
+// None for the eigenvectors of clusters it could not resolve
+pub fn representation_tree(
+    d: VectorView,
+    e: VectorView,
+) -> Option<(Vec<f64>, Vec<Option<Vector>>)> {
+    let n = d.shape()[0];
+
+    // root representation L D L^T = T - sigma I, positive definite with sigma just below the spectrum
+    let norm = tridiagonal_norm(d, e);
+    let lower = bisection(d, e, 0);
+    let mut delta = f64::EPSILON * norm * n as f64;
+    let (mut ld, mut ll) = (vec![0.; n], vec![0.; n - 1]);
+    let mut sigma = lower;
+    let mut definite = false;
+
+    while !definite && delta.is_finite() {
+        sigma = lower - delta;
+        ld[0] = d[0] - sigma;
+        for i in 0..n - 1 {
+            ll[i] = e[i] / ld[i];
+            ld[i + 1] = d[i + 1] - sigma - ll[i] * e[i];
+        }
+        definite = ld.iter().all(|x| *x > 0.);
+        delta *= 4.;
+    }
+    if !definite {
+        return None;
+    }
+
+    // deterministic, so that the results are reproducible
+    let mut rng = StdRng::seed_from_u64(n as u64);
+    let noise = Uniform::new(-ROOT_PERTURBATION, ROOT_PERTURBATION);
+    let pd = Array::random_using(n, noise, &mut rng);
+    let pl = Array::random_using(n - 1, noise, &mut rng);
+    ld.iter_mut()
+        .zip(pd.iter())
+        .for_each(|(x, p)| *x *= 1. + p * f64::EPSILON);
+    ll.iter_mut()
+        .zip(pl.iter())
+        .for_each(|(x, p)| *x *= 1. + p * f64::EPSILON);
+
+    let mut q = ld.clone();
+    let mut qe: Vec<f64> = (0..n - 1).map(|i| ld[i] * ll[i] * ll[i]).collect();
+    if !dqds(&mut q, &mut qe) {
+        return None;
+    }
+    q.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let idx: Vec<usize> = (0..n).collect();
+    let vectors = representation_vectors(&ld, &ll, &q, &idx, 4. * f64::EPSILON, 0);
+    let values: Vec<f64> = q.iter().map(|x| sigma + x).collect();
+    Some((values, vectors))
+}
+
+// Eigendecomposition of an unreduced block; outputs (ascending eigenvalues, eigenvectors)
+fn mrrr_block(d: VectorView, e: VectorView, opts: &QROptions) -> Option<(Vec<f64>, Matrix)> {
+    let n = d.shape()[0];
+    if n == 1 {
+        return Some((vec![d[0]], Matrix::eye(1)));
+    }
+
+    let (values, vectors) = representation_tree(d, e)?;
+
+    // inverse iteration for the clusters the representation tree could not resolve completely,
+    // since an unresolved vector would not be orthogonal to the resolved ones of its cluster
+    let mut u = Matrix::zeros((n, n));
+    let mut i = 0;
+    while i < n {
+        let j = cluster_end(&values, i);
+        if vectors[i..=j].iter().any(|v| v.is_none()) {
+            let z: Vector = values[i..=j].iter().copied().collect();
+            let x = inverse_iteration(d, e, z.view(), opts)?;
+            u.slice_mut(s![.., i..=j]).assign(&x);
+        } else {
+            for (m, v) in vectors[i..=j].iter().enumerate() {
+                u.column_mut(i + m).assign(v.as_ref().unwrap());
+            }
+        }
+        i = j + 1;
+    }
+
+    Some((values, u))
+}
+
+pub fn mrrr(mut d: VectorViewMut, e: VectorView, opts: &QROptions) -> Option<Matrix> {
+    let n = d.shape()[0];
+    let mut u = Matrix::zeros((n, n));
+
+    let mut start = 0;
+    for end in 1..=n {
+        let split =
+            end == n || e[end - 1].abs() <= f64::EPSILON * (d[end - 1].abs() + d[end].abs());
+        if !split {
+            continue;
+        }
+
+        let (values, z) = mrrr_block(d.slice(s![start..end]), e.slice(s![start..end - 1]), opts)?;
+        d.slice_mut(s![start..end])
+            .iter_mut()
+            .zip(values.iter())
+            .for_each(|(x, y)| *x = *y);
+        u.slice_mut(s![start..end, start..end]).assign(&z);
+        start = end;
+    }
+
+    Some(u)
+}
+
+pub fn mrrr_symmetric(mut m: MatrixViewMut, mut u: MatrixViewMut, opts: &QROptions) -> bool {
+    let n = m.shape()[0];
+    let mut d = m.diag().into_owned();
+    let e: Vector = (1..n).map(|k| m[[k, k - 1]]).collect();
+
+    let z = match mrrr(d.view_mut(), e.view(), opts) {
+        Some(z) => z,
+        None => return false,
+    };
+
+    m.fill(0.);
+    m.diag_mut().assign(&d);
+    if opts.accumulate_sim_transforms {
+        let uz = u.dot(&z);
+        u.assign(&uz);
+    }
+    true
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+const NEWTON_STEPS: usize = 5;
+
+pub fn companion_matrix(c: VectorView) -> Matrix {
+    let d = c.shape()[0] - 1;
+    let mut m = Matrix::zeros((d, d));
+    for j in 0..d {
+        m[[0, j]] = -c[j + 1] / c[0];
+    }
+    for i in 1..d {
+        m[[i, i - 1]] = 1.;
+    }
+    m
+}
+
+pub fn francis_converged(m: MatrixView) -> bool {
+    let n = m.shape()[0];
+    (0..n.saturating_sub(2)).all(|i| m[[i + 1, i]] == 0. || m[[i + 2, i + 1]] == 0.)
+}
+
+// Returns (p(z), p'(z)) by Horner's scheme
+fn horner(c: VectorView, z: Complex) -> (Complex, Complex) {
+    let (mut p, mut dp) = (Complex::from(0.), Complex::from(0.));
+    for &x in c.iter() {
+        dp = dp * z + p;
+        p = p * z + x;
+    }
+    (p, dp)
+}
+
+pub fn newton_polish(c: VectorView, root: Complex) -> Complex {
+    let mut z = root;
+    let (mut p, mut dp) = horner(c, z);
+    for _ in 0..NEWTON_STEPS {
+        if p.norm() == 0. || dp.norm() == 0. {
+            break;
+        }
+        let next = z - p / dp;
+        let (np, ndp) = horner(c, next);
+        if np.norm().is_nan() || np.norm() >= p.norm() {
+            break;
+        }
+        z = next;
+        p = np;
+        dp = ndp;
+    }
+    z
+}
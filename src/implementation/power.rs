@@ -0,0 +1,74 @@
+use crate::implementation::common::norm;
+use crate::implementation::lu::{lu_decomposition, lu_solve};
+use crate::*;
+
+use ndarray::Axis;
+
+// Power-type iteration x <- step(x, A x, l) / |step(x, A x, l)| with the Rayleigh quotient l = x^T A x;
+// stops once |A x - l x| <= eps |A|, estimating |A| by the largest |A x| seen,
+// or when step fails. Outputs (l, x, info, converged)
+pub fn vector_iteration<F, G>(
+    apply: F,
+    mut step: G,
+    x0: VectorView,
+    opts: &QROptions,
+) -> (f64, Vector, IterationInfo, bool)
+where
+    F: Fn(VectorView) -> Vector,
+    G: FnMut(VectorView, VectorView, f64) -> Option<Vector>,
+{
+    let mut x = x0.to_owned() / norm(x0);
+    let mut scale: f64 = 0.;
+    let mut iterations = 0;
+
+    loop {
+        let y = apply(x.view());
+        let lambda = x.dot(&y);
+        let residual = norm((&y - &(lambda * &x)).view());
+        scale = scale.max(norm(y.view()));
+
+        let converged = residual <= opts.eps * scale;
+        let info = IterationInfo {
+            iterations,
+            residual,
+        };
+        if converged || !residual.is_finite() || iterations >= opts.iterations {
+            return (lambda, x, info, converged);
+        }
+
+        let v = match step(x.view(), y.view(), lambda) {
+            Some(v) => v,
+            None => return (lambda, x, info, false),
+        };
+        let n = norm(v.view());
+        if !(n > 0. && n.is_finite()) {
+            return (lambda, x, info, false);
+        }
+        x = v / n;
+        iterations += 1;
+    }
+}
+
+// LU decomposition of A - sI with tiny pivots replaced by eps |A|, so that solves near eigenvalues stay finite
+pub fn shifted_lu(m: MatrixView, s: f64) -> (Matrix, Vec<usize>) {
+    let n = m.shape()[0];
+    let mut lu = m.into_owned();
+    for i in 0..n {
+        lu[[i, i]] -= s;
+    }
+    let tiny = f64::EPSILON * lu.iter().fold(f64::MIN_POSITIVE, |acc, x| acc.max(x.abs()));
+
+    let (perm, _) = lu_decomposition(lu.view_mut());
+    for i in 0..n {
+        if lu[[i, i]].abs() < tiny {
+            lu[[i, i]] = tiny.copysign(lu[[i, i]]);
+        }
+    }
+
+    (lu, perm)
+}
+
+// Solves (A - sI) x = b with the output of shifted_lu
+pub fn shifted_solve(lu: MatrixView, perm: &[usize], b: VectorView) -> Vector {
+    lu_solve(lu, perm, b.insert_axis(Axis(1))).index_axis_move(Axis(1), 0)
+}
@@ -1,4 +1,3 @@
-use crate::implementation::blocks::*;
 use crate::implementation::common::*;
 use crate::implementation::givens::*;
 use crate::*;
@@ -6,20 +5,22 @@ use crate::*;
 use ndarray::s;
 
 #[inline]
-fn wilkinson_shift(m: MatrixView, p: usize) -> f64 {
-    let a = m[[p, p]];
-    let b = m[[p, p - 1]];
-    let d = 0.5 * (m[[p - 1, p - 1]] - a);
-    if d == 0. {
+fn wilkinson_shift(d: VectorView, e: VectorView, p: usize) -> f64 {
+    let a = d[p];
+    let b = e[p - 1];
+    let h = 0.5 * (d[p - 1] - a);
+    if h == 0. {
         a - b.abs()
     } else {
-        a - b * b / (d + d.signum() * d.hypot(b))
+        a - b * b / (h + h.signum() * h.hypot(b))
     }
 }
 
 #[inline]
+#[allow(clippy::too_many_arguments)]
 fn implicit_tridiagonal_rotation(
-    mut m: MatrixViewMut,
+    mut d: VectorViewMut,
+    mut e: VectorViewMut,
     x: &mut f64,
     y: &mut f64,
     c: f64,
@@ -28,55 +29,99 @@ fn implicit_tridiagonal_rotation(
     p: usize,
 ) {
     let w = c * *x - s * *y;
-    let d = m[[k, k]] - m[[k + 1, k + 1]];
-    let z = (2. * c * m[[k + 1, k]] + d * s) * s;
+    let h = d[k] - d[k + 1];
+    let z = (2. * c * e[k] + h * s) * s;
 
-    m[[k, k]] -= z;
-    m[[k + 1, k + 1]] += z;
-    m[[k + 1, k]] = d * c * s + (c * c - s * s) * m[[k + 1, k]];
-    m[[k, k + 1]] = m[[k + 1, k]];
-    *x = m[[k + 1, k]];
+    d[k] -= z;
+    d[k + 1] += z;
+    e[k] = h * c * s + (c * c - s * s) * e[k];
+    *x = e[k];
 
     if k > 0 {
-        m[[k - 1, k]] = w;
-        m[[k, k - 1]] = w;
+        e[k - 1] = w;
     }
 
     if k + 1 < p {
-        *y = -s * m[[k + 2, k + 1]];
-        m[[k + 2, k + 1]] *= c;
-        m[[k + 1, k + 2]] *= c;
+        *y = -s * e[k + 1];
+        e[k + 1] *= c;
     }
 }
 
-fn symmetric_qr_step(mut m: MatrixViewMut, mut u: MatrixViewMut, p: usize, s: f64) {
-    let n = m.shape()[0];
-    let mut x = m[[0, 0]] - s;
-    let mut y = m[[0, 1]];
+fn symmetric_qr_step(
+    mut d: VectorViewMut,
+    mut e: VectorViewMut,
+    mut u: Option<MatrixViewMut>,
+    p: usize,
+    s: f64,
+) {
+    let mut x = d[0] - s;
+    let mut y = e[0];
 
     for k in 0..p {
-        let (c, s) = if p > 1 {
-            givens(x, y)
-        } else {
-            rot_2by2(m.slice(s![0..2, 0..2]))
-        };
-
-        implicit_tridiagonal_rotation(m.view_mut(), &mut x, &mut y, c, s, k, p);
-        givens_rot_right((c, s), u.slice_mut(s![0..n, k..k + 2]));
+        let (c, s) = givens(x, y);
+        implicit_tridiagonal_rotation(d.view_mut(), e.view_mut(), &mut x, &mut y, c, s, k, p);
+        if let Some(u) = u.as_mut() {
+            givens_rot_right((c, s), u.slice_mut(s![.., k..k + 2]));
+        }
     }
 }
 
-pub fn qr_algorithm_symmetric(mut m: MatrixViewMut, mut u: MatrixViewMut, opts: &QROptions) {
-    let n = m.shape()[0];
+pub fn qr_algorithm_tridiagonal(
+    mut d: VectorViewMut,
+    mut e: VectorViewMut,
+    mut u: Option<MatrixViewMut>,
+    opts: &QROptions,
+) -> bool {
+    let n = d.shape()[0];
+    if n == 0 {
+        return true;
+    }
+
+    let scale = d.iter().chain(e.iter()).map(|x| x * x).sum::<f64>().sqrt();
+    let floor = f64::EPSILON * scale;
     let mut p = n - 1;
     let mut i = 0;
 
+    let negligible = |d: &VectorViewMut, e: &VectorViewMut, k: usize| {
+        eigval_collapsed(opts.eps, e[k], d[k], d[k + 1]) || e[k].abs() <= floor
+    };
+
     while p > 0 && i < opts.iterations {
-        let s = wilkinson_shift(m.view(), p);
-        symmetric_qr_step(m.view_mut(), u.view_mut(), p, s);
-        if eigval_collapsed(opts.eps, m[[p, p - 1]], m[[p - 1, p - 1]], m[[p, p]]) {
+        if negligible(&d, &e, p - 1) {
             p -= 1;
+            continue;
         }
+
+        // the step is restricted to the unreduced block ending at p
+        let mut l = p - 1;
+        while l > 0 && !negligible(&d, &e, l - 1) {
+            l -= 1;
+        }
+
+        let s = wilkinson_shift(d.slice(s![l..]), e.slice(s![l..]), p - l);
+        symmetric_qr_step(
+            d.slice_mut(s![l..=p]),
+            e.slice_mut(s![l..p]),
+            u.as_mut().map(|u| u.slice_mut(s![.., l..=p])),
+            p - l,
+            s,
+        );
         i += 1;
     }
+
+    p == 0
+}
+
+pub fn qr_algorithm_symmetric(mut m: MatrixViewMut, u: MatrixViewMut, opts: &QROptions) {
+    let n = m.shape()[0];
+    let mut d = m.diag().into_owned();
+    let mut e: Vector = (1..n).map(|k| m[[k, k - 1]]).collect();
+
+    qr_algorithm_tridiagonal(d.view_mut(), e.view_mut(), Some(u), opts);
+
+    m.diag_mut().assign(&d);
+    for k in 1..n {
+        m[[k, k - 1]] = e[k - 1];
+        m[[k - 1, k]] = e[k - 1];
+    }
 }
@@ -0,0 +1,99 @@
+use crate::implementation::qr_symmetric::qr_algorithm_tridiagonal;
+use crate::*;
+
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+// Lanczos approximation, g = 7, n = 9
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+pub fn gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        return PI / ((PI * x).sin() * gamma(1. - x));
+    }
+
+    let x = x - 1.;
+    let t = x + 7.5;
+    let sum = (1..9).fold(LANCZOS[0], |acc, i| acc + LANCZOS[i] / (x + i as f64));
+    (2. * PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * sum
+}
+
+// Diagonal a_k, squared off-diagonal b_k of the Jacobi matrix and the total weight mu_0
+pub fn recurrence(rule: QuadratureRule, n: usize) -> (Vector, Vector, f64) {
+    let mut a = Vector::zeros(n);
+    let mut b = Vector::zeros(n);
+
+    let mu = match rule {
+        QuadratureRule::Legendre => {
+            for k in 1..n {
+                let k = k as f64;
+                b[k as usize] = k * k / (4. * k * k - 1.);
+            }
+            2.
+        }
+        QuadratureRule::Hermite => {
+            for k in 1..n {
+                b[k] = k as f64 / 2.;
+            }
+            PI.sqrt()
+        }
+        QuadratureRule::Laguerre(al) => {
+            for k in 0..n {
+                let kf = k as f64;
+                a[k] = 2. * kf + al + 1.;
+                b[k] = kf * (kf + al);
+            }
+            gamma(al + 1.)
+        }
+        QuadratureRule::Jacobi(al, be) => {
+            let ab = al + be;
+            for k in 0..n {
+                let kf = k as f64;
+                let s = 2. * kf + ab;
+                a[k] = if k == 0 {
+                    (be - al) / (ab + 2.)
+                } else {
+                    (be * be - al * al) / (s * (s + 2.))
+                };
+                b[k] = match k {
+                    0 => 0.,
+                    1 => 4. * (1. + al) * (1. + be) / ((2. + ab) * (2. + ab) * (3. + ab)),
+                    _ => {
+                        4. * kf * (kf + al) * (kf + be) * (kf + ab) / (s * s * (s + 1.) * (s - 1.))
+                    }
+                };
+            }
+            2f64.powf(ab + 1.) * gamma(al + 1.) * gamma(be + 1.) / gamma(ab + 2.)
+        }
+    };
+
+    (a, b, mu)
+}
+
+pub fn golub_welsch(rule: QuadratureRule, n: usize, opts: &QROptions) -> Option<(Vector, Vector)> {
+    let (mut d, b, mu) = recurrence(rule, n);
+    let mut e: Vector = b.iter().skip(1).map(|x| x.sqrt()).collect();
+
+    // Only the first components of the eigenvectors are needed, so only the first row of U is accumulated
+    let mut u = Matrix::zeros((1, n));
+    u[[0, 0]] = 1.;
+    if !qr_algorithm_tridiagonal(d.view_mut(), e.view_mut(), Some(u.view_mut()), opts) {
+        return None;
+    }
+
+    let mut perm: Vec<usize> = (0..n).collect();
+    perm.sort_by(|i, j| d[*i].partial_cmp(&d[*j]).unwrap_or(Ordering::Equal));
+    let nodes = perm.iter().map(|i| d[*i]).collect();
+    let weights = perm.iter().map(|i| mu * u[[0, *i]] * u[[0, *i]]).collect();
+    Some((nodes, weights))
+}
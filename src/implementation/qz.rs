@@ -0,0 +1,460 @@
+use crate::implementation::checks::frob_norm;
+use crate::implementation::common::*;
+use crate::implementation::givens::*;
+use crate::implementation::householder::*;
+use crate::*;
+
+use ndarray::{array, s};
+use std::cmp::{max, min};
+
+#[inline]
+fn householder_vec_rev(x: VectorView) -> Vector {
+    let rev: Vector = x.iter().rev().cloned().collect();
+    householder_vec(rev.view()).iter().rev().cloned().collect()
+}
+
+#[inline]
+fn rot_left_pair(
+    rot: (f64, f64),
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    k: usize,
+    c: usize,
+    acc: bool,
+) {
+    let n = a.shape()[0];
+    givens_rot_left(rot, a.slice_mut(s![k..k + 2, c..n]));
+    givens_rot_left(rot, b.slice_mut(s![k..k + 2, c..n]));
+    if acc {
+        givens_rot_right(rot, q.slice_mut(s![0..n, k..k + 2]));
+    }
+}
+
+#[inline]
+fn rot_right_pair(
+    rot: (f64, f64),
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut z: MatrixViewMut,
+    k: usize,
+    r: usize,
+    acc: bool,
+) {
+    let n = a.shape()[0];
+    givens_rot_right(rot, a.slice_mut(s![0..r, k..k + 2]));
+    givens_rot_right(rot, b.slice_mut(s![0..r, k..k + 2]));
+    if acc {
+        givens_rot_right(rot, z.slice_mut(s![0..n, k..k + 2]));
+    }
+}
+
+pub fn hessenberg_triangular_form(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    opts: &QROptions,
+) {
+    let n = a.shape()[0];
+    let acc = opts.accumulate_sim_transforms;
+
+    for k in 0..n.saturating_sub(1) {
+        let v = householder_vec(b.slice(s![k..n, k]));
+        householder_refl_left(v.view(), b.slice_mut(s![k..n, k..n]));
+        householder_refl_left(v.view(), a.slice_mut(s![k..n, 0..n]));
+        if acc {
+            householder_refl_right(v.view(), q.slice_mut(s![0..n, k..n]));
+        }
+        b.slice_mut(s![k + 1..n, k]).fill(0.);
+    }
+
+    for j in 0..n.saturating_sub(2) {
+        for i in (j + 2..n).rev() {
+            let rot = givens(a[[i - 1, j]], a[[i, j]]);
+            rot_left_pair(rot, a.view_mut(), b.view_mut(), q.view_mut(), i - 1, j, acc);
+            a[[i, j]] = 0.;
+
+            let rot = givens(b[[i, i]], -b[[i, i - 1]]);
+            rot_right_pair(rot, a.view_mut(), b.view_mut(), z.view_mut(), i - 1, n, acc);
+            b[[i, i - 1]] = 0.;
+        }
+    }
+}
+
+fn upper_triangular_inverse_3by3(b: MatrixView) -> Matrix {
+    let (b00, b01, b02, b11, b12, b22) = (
+        b[[0, 0]],
+        b[[0, 1]],
+        b[[0, 2]],
+        b[[1, 1]],
+        b[[1, 2]],
+        b[[2, 2]],
+    );
+    let i01 = -b01 / (b00 * b11);
+    let i12 = -b12 / (b11 * b22);
+    let i02 = (b01 * b12 - b02 * b11) / (b00 * b11 * b22);
+    array![
+        [1. / b00, i01, i02],
+        [0., 1. / b11, i12],
+        [0., 0., 1. / b22]
+    ]
+}
+
+fn qz_shift_vector(
+    a: MatrixView,
+    b: MatrixView,
+    lo: usize,
+    hi: usize,
+    exceptional: bool,
+) -> Vector {
+    let binv = upper_triangular_inverse_3by3(b.slice(s![hi - 2..hi + 1, hi - 2..hi + 1]));
+    let mt = a
+        .slice(s![hi - 1..hi + 1, hi - 2..hi + 1])
+        .dot(&binv.slice(s![.., 1..3]));
+    let (mut tr, mut det) = (
+        mt[[0, 0]] + mt[[1, 1]],
+        mt[[0, 0]] * mt[[1, 1]] - mt[[0, 1]] * mt[[1, 0]],
+    );
+
+    if exceptional {
+        let w = mt[[1, 0]].abs() + (a[[hi - 1, hi - 2]] * binv[[0, 0]]).abs();
+        tr = 1.5 * w;
+        det = w * w;
+    }
+
+    let (b00, b01, b11) = (b[[lo, lo]], b[[lo, lo + 1]], b[[lo + 1, lo + 1]]);
+    let m00 = a[[lo, lo]] / b00;
+    let m10 = a[[lo + 1, lo]] / b00;
+    let m01 = a[[lo, lo + 1]] / b11 - a[[lo, lo]] * b01 / (b00 * b11);
+    let m11 = a[[lo + 1, lo + 1]] / b11 - a[[lo + 1, lo]] * b01 / (b00 * b11);
+    let m21 = a[[lo + 2, lo + 1]] / b11;
+
+    array![
+        m00 * m00 + m01 * m10 - tr * m00 + det,
+        m10 * (m00 + m11 - tr),
+        m21 * m10
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+fn qz_step(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    lo: usize,
+    hi: usize,
+    mut v: Vector,
+    acc: bool,
+) {
+    let n = a.shape()[0];
+    for k in lo..hi - 1 {
+        let c = max(lo, k.saturating_sub(1));
+        let refl = householder_vec(v.view());
+        householder_refl_left(refl.view(), a.slice_mut(s![k..k + 3, c..n]));
+        householder_refl_left(refl.view(), b.slice_mut(s![k..k + 3, c..n]));
+        if acc {
+            householder_refl_right(refl.view(), q.slice_mut(s![0..n, k..k + 3]));
+        }
+        if k > lo {
+            a[[k + 1, k - 1]] = 0.;
+            a[[k + 2, k - 1]] = 0.;
+        }
+
+        let r = min(k + 4, hi + 1);
+        let refl = householder_vec_rev(b.slice(s![k + 2, k..k + 3]));
+        householder_refl_right(refl.view(), a.slice_mut(s![0..r, k..k + 3]));
+        householder_refl_right(refl.view(), b.slice_mut(s![0..r, k..k + 3]));
+        if acc {
+            householder_refl_right(refl.view(), z.slice_mut(s![0..n, k..k + 3]));
+        }
+        b[[k + 2, k]] = 0.;
+        b[[k + 2, k + 1]] = 0.;
+
+        let refl = householder_vec_rev(b.slice(s![k + 1, k..k + 2]));
+        householder_refl_right(refl.view(), a.slice_mut(s![0..r, k..k + 2]));
+        householder_refl_right(refl.view(), b.slice_mut(s![0..r, k..k + 2]));
+        if acc {
+            householder_refl_right(refl.view(), z.slice_mut(s![0..n, k..k + 2]));
+        }
+        b[[k + 1, k]] = 0.;
+
+        v[0] = a[[k + 1, k]];
+        v[1] = a[[k + 2, k]];
+        if k + 2 < hi {
+            v[2] = a[[k + 3, k]];
+        }
+    }
+
+    let rot = givens(v[0], v[1]);
+    rot_left_pair(
+        rot,
+        a.view_mut(),
+        b.view_mut(),
+        q.view_mut(),
+        hi - 1,
+        hi - 2,
+        acc,
+    );
+    a[[hi, hi - 2]] = 0.;
+
+    let rot = givens(b[[hi, hi]], -b[[hi, hi - 1]]);
+    rot_right_pair(
+        rot,
+        a.view_mut(),
+        b.view_mut(),
+        z.view_mut(),
+        hi - 1,
+        hi + 1,
+        acc,
+    );
+    b[[hi, hi - 1]] = 0.;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn chase_infinite_eigenvalue(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    j: usize,
+    lo: usize,
+    hi: usize,
+    acc: bool,
+) {
+    for k in j..hi {
+        let rot = givens(b[[k, k + 1]], b[[k + 1, k + 1]]);
+        rot_left_pair(
+            rot,
+            a.view_mut(),
+            b.view_mut(),
+            q.view_mut(),
+            k,
+            max(lo, k.saturating_sub(1)),
+            acc,
+        );
+        b[[k + 1, k + 1]] = 0.;
+
+        if k > lo {
+            let rot = givens(a[[k + 1, k]], -a[[k + 1, k - 1]]);
+            rot_right_pair(
+                rot,
+                a.view_mut(),
+                b.view_mut(),
+                z.view_mut(),
+                k - 1,
+                k + 2,
+                acc,
+            );
+            a[[k + 1, k - 1]] = 0.;
+        }
+    }
+
+    let rot = givens(a[[hi, hi]], -a[[hi, hi - 1]]);
+    rot_right_pair(
+        rot,
+        a.view_mut(),
+        b.view_mut(),
+        z.view_mut(),
+        hi - 1,
+        hi + 1,
+        acc,
+    );
+    a[[hi, hi - 1]] = 0.;
+}
+
+pub fn qz_algorithm(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    opts: &QROptions,
+) {
+    let n = a.shape()[0];
+    if n < 2 {
+        return;
+    }
+
+    let acc = opts.accumulate_sim_transforms;
+    let anorm = frob_norm(a.view());
+    let btol = opts.eps * frob_norm(b.view());
+    let (mut hi, mut i, mut since_deflation) = (n - 1, 0, 0);
+
+    while hi > 0 && i < opts.iterations {
+        let mut lo = hi;
+        while lo > 0 {
+            let sub = a[[lo, lo - 1]];
+            if eigval_collapsed(opts.eps, sub, a[[lo - 1, lo - 1]], a[[lo, lo]])
+                || sub.abs() <= f64::EPSILON * anorm
+            {
+                a[[lo, lo - 1]] = 0.;
+                break;
+            }
+            lo -= 1;
+        }
+
+        if lo == hi {
+            hi -= 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        if let Some(j) = (lo..hi + 1).find(|&j| b[[j, j]].abs() <= btol) {
+            b[[j, j]] = 0.;
+            chase_infinite_eigenvalue(
+                a.view_mut(),
+                b.view_mut(),
+                q.view_mut(),
+                z.view_mut(),
+                j,
+                lo,
+                hi,
+                acc,
+            );
+            hi -= 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        if hi - lo == 1 {
+            if lo == 0 {
+                break;
+            }
+            hi = lo - 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        since_deflation += 1;
+        let v = qz_shift_vector(a.view(), b.view(), lo, hi, since_deflation % 11 == 10);
+        qz_step(
+            a.view_mut(),
+            b.view_mut(),
+            q.view_mut(),
+            z.view_mut(),
+            lo,
+            hi,
+            v,
+            acc,
+        );
+        i += 1;
+    }
+}
+
+// Coefficients of det(A - l B) = qa l^2 - qb l + qc for the 2 by 2 block of (A, B) at i
+#[inline]
+fn block_polynomial(a: MatrixView, b: MatrixView, i: usize) -> (f64, f64, f64) {
+    let (a00, a01, a10, a11) = (a[[i, i]], a[[i, i + 1]], a[[i + 1, i]], a[[i + 1, i + 1]]);
+    let (b00, b01, b11) = (b[[i, i]], b[[i, i + 1]], b[[i + 1, i + 1]]);
+    (
+        b00 * b11,
+        a00 * b11 + a11 * b00 - a10 * b01,
+        a00 * a11 - a01 * a10,
+    )
+}
+
+// Real roots of qa l^2 - qb l + qc as unit pairs (alpha, beta) with beta >= 0, or None for a complex pair.
+// qa = 0 (singular B block) gives infinite roots with beta = 0
+fn real_block_roots(qa: f64, qb: f64, qc: f64) -> Option<[(f64, f64); 2]> {
+    let d = qb * qb - 4. * qa * qc;
+    if d < 0. {
+        return None;
+    }
+
+    let s = qb + qb.signum() * d.sqrt();
+    let roots = if s != 0. {
+        [(s, 2. * qa), (2. * qc, s)]
+    } else if qa == 0. {
+        [(1., 0.), (1., 0.)]
+    } else {
+        [(0., 1.), (0., 1.)]
+    };
+
+    Some(roots.map(|(alpha, beta)| {
+        let h = alpha.hypot(beta) * beta.signum();
+        (alpha / h, beta / h)
+    }))
+}
+
+pub fn qz_block_reduction(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    opts: &QROptions,
+) {
+    let acc = opts.accumulate_sim_transforms;
+    for (i, sz) in schur_blocks(a.view()) {
+        if sz == 1 {
+            continue;
+        }
+
+        let (qa, qb, qc) = block_polynomial(a.view(), b.view(), i);
+        let (alpha, beta) = match real_block_roots(qa, qb, qc) {
+            Some(roots) => roots[0],
+            None => continue,
+        };
+
+        // the null vector of beta A - alpha B moves the eigenvalue (alpha, beta) to the top
+        let (a00, a01, a10, a11) = (a[[i, i]], a[[i, i + 1]], a[[i + 1, i]], a[[i + 1, i + 1]]);
+        let (b00, b01, b11) = (b[[i, i]], b[[i, i + 1]], b[[i + 1, i + 1]]);
+        let (c00, c01, c10, c11) = (
+            beta * a00 - alpha * b00,
+            beta * a01 - alpha * b01,
+            beta * a10,
+            beta * a11 - alpha * b11,
+        );
+        let v = if c00.hypot(c01) >= c10.hypot(c11) {
+            (c01, -c00)
+        } else {
+            (c11, -c10)
+        };
+        let h = v.0.hypot(v.1);
+        let rot = if h == 0. {
+            (1., 0.)
+        } else {
+            (v.0 / h, -v.1 / h)
+        };
+        rot_right_pair(rot, a.view_mut(), b.view_mut(), z.view_mut(), i, i + 2, acc);
+
+        let w = if a[[i, i]].hypot(a[[i + 1, i]]) >= b[[i, i]].hypot(b[[i + 1, i]]) {
+            (a[[i, i]], a[[i + 1, i]])
+        } else {
+            (b[[i, i]], b[[i + 1, i]])
+        };
+        let rot = givens(w.0, w.1);
+        rot_left_pair(rot, a.view_mut(), b.view_mut(), q.view_mut(), i, i, acc);
+        a[[i + 1, i]] = 0.;
+        b[[i + 1, i]] = 0.;
+    }
+}
+
+pub fn extract_generalized_eigenvalues(a: MatrixView, b: MatrixView) -> Vec<(Complex, f64)> {
+    let mut eigs = Vec::new();
+    for (i, sz) in schur_blocks(a) {
+        if sz == 1 {
+            let sgn = if b[[i, i]] < 0. { -1. } else { 1. };
+            eigs.push((Complex::from(sgn * a[[i, i]]), sgn * b[[i, i]]));
+            continue;
+        }
+
+        let (qa, qb, qc) = block_polynomial(a, b, i);
+        if let Some(roots) = real_block_roots(qa, qb, qc) {
+            // unreduced block with real or infinite eigenvalues, scaled like the entries of the pencil
+            let scale = frob_norm(a.slice(s![i..i + 2, i..i + 2]))
+                .hypot(frob_norm(b.slice(s![i..i + 2, i..i + 2])));
+            for (alpha, beta) in roots.iter() {
+                eigs.push((Complex::from(alpha * scale), beta * scale));
+            }
+            continue;
+        }
+
+        let beta = qa.abs().sqrt();
+        let re = qb / (2. * qa);
+        let im = (4. * qa * qc - qb * qb).max(0.).sqrt() / (2. * qa.abs());
+        eigs.push((Complex::new(re, im) * beta, beta));
+        eigs.push((Complex::new(re, -im) * beta, beta));
+    }
+    eigs
+}
@@ -0,0 +1,252 @@
+use crate::implementation::blocks::*;
+use crate::implementation::checks::frob_norm;
+use crate::implementation::givens::*;
+use crate::implementation::householder::*;
+use crate::implementation::sylvester::*;
+use crate::*;
+
+use ndarray::s;
+
+fn standardize_block(mut t: MatrixViewMut, mut u: MatrixViewMut, i: usize) {
+    let n = t.shape()[0];
+    let rot = rot_2by2(t.slice(s![i..i + 2, i..i + 2]));
+    givens_rot_left(rot, t.slice_mut(s![i..i + 2, i..n]));
+    givens_rot_right(rot, t.slice_mut(s![0..i + 2, i..i + 2]));
+    givens_rot_right(rot, u.slice_mut(s![0..n, i..i + 2]));
+
+    let scale = t[[i, i]].abs() + t[[i + 1, i + 1]].abs();
+    if t[[i + 1, i]].abs() <= f64::EPSILON * scale || t[[i + 1, i]] * t[[i, i + 1]] >= 0. {
+        t[[i + 1, i]] = 0.;
+    }
+}
+
+// Orthogonal matrix whose leading columns span the columns of [X; I]
+fn complement_basis(x: MatrixView) -> Matrix {
+    let (p, q) = (x.shape()[0], x.shape()[1]);
+    let nd = p + q;
+    let mut w = Matrix::zeros((nd, q));
+    w.slice_mut(s![0..p, ..]).assign(&x);
+    w.slice_mut(s![p..nd, ..]).assign(&Matrix::eye(q));
+
+    let mut qm = Matrix::eye(nd);
+    for c in 0..q {
+        let v = householder_vec(w.slice(s![c..nd, c]));
+        householder_refl_left(v.view(), w.slice_mut(s![c..nd, c..q]));
+        householder_refl_right(v.view(), qm.slice_mut(s![0..nd, c..nd]));
+    }
+    qm
+}
+
+pub fn swap_schur_blocks(
+    mut t: MatrixViewMut,
+    mut u: MatrixViewMut,
+    j: usize,
+    p: usize,
+    q: usize,
+) -> bool {
+    let n = t.shape()[0];
+    let nd = p + q;
+    let d = t.slice(s![j..j + nd, j..j + nd]).into_owned();
+
+    let mut x = d.slice(s![0..p, p..nd]).into_owned();
+    sylvester_quasi_triangular(
+        d.slice(s![0..p, 0..p]),
+        d.slice(s![p..nd, p..nd]),
+        x.view_mut(),
+        -1.,
+    );
+
+    let qm = complement_basis((-&x).view());
+
+    let swapped = qm.t().dot(&d).dot(&qm);
+    let thresh = (10. * f64::EPSILON * frob_norm(d.view())).max(f64::MIN_POSITIVE);
+    if frob_norm(swapped.slice(s![q..nd, 0..q])) > thresh {
+        return false;
+    }
+
+    let rows = qm.t().dot(&t.slice(s![j..j + nd, j..n]));
+    t.slice_mut(s![j..j + nd, j..n]).assign(&rows);
+    let cols = t.slice(s![0..j + nd, j..j + nd]).dot(&qm);
+    t.slice_mut(s![0..j + nd, j..j + nd]).assign(&cols);
+    let cols = u.slice(s![0..n, j..j + nd]).dot(&qm);
+    u.slice_mut(s![0..n, j..j + nd]).assign(&cols);
+
+    t.slice_mut(s![j + q..j + nd, j..j + q]).fill(0.);
+    if q == 2 {
+        standardize_block(t.view_mut(), u.view_mut(), j);
+    }
+    if p == 2 {
+        standardize_block(t.view_mut(), u.view_mut(), j + q);
+    }
+
+    true
+}
+
+#[inline]
+fn block_size(t: MatrixView, k: usize) -> usize {
+    if k + 1 < t.shape()[0] && t[[k + 1, k]] != 0. {
+        2
+    } else {
+        1
+    }
+}
+
+pub fn reorder_schur(mut t: MatrixViewMut, mut u: MatrixViewMut, select: &[bool]) -> Option<usize> {
+    let n = t.shape()[0];
+    let (mut k, mut ks) = (0, 0);
+
+    while k < n {
+        let sz = block_size(t.view(), k);
+        if select[k] || (sz == 2 && select[k + 1]) {
+            let mut here = k;
+            while here > ks {
+                let prev = if here >= 2 && t[[here - 1, here - 2]] != 0. {
+                    2
+                } else {
+                    1
+                };
+
+                if !swap_schur_blocks(t.view_mut(), u.view_mut(), here - prev, prev, sz) {
+                    return None;
+                }
+                here -= prev;
+            }
+            ks += sz;
+        }
+        k += sz;
+    }
+
+    Some(ks)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn swap_generalized_schur_blocks(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    j: usize,
+    p: usize,
+    r: usize,
+) -> bool {
+    let n = a.shape()[0];
+    let nd = p + r;
+    let da = a.slice(s![j..j + nd, j..j + nd]).into_owned();
+    let db = b.slice(s![j..j + nd, j..j + nd]).into_owned();
+
+    // A11 R - L A22 = -A12, B11 R - L B22 = -B12; unknowns R then L, column-major
+    let k = p * r;
+    let mut sys = Matrix::zeros((2 * k, 2 * k));
+    let mut rhs = Vector::zeros(2 * k);
+    for (e, d) in [&da, &db].iter().enumerate() {
+        for c in 0..r {
+            for i in 0..p {
+                let row = e * k + i + p * c;
+                rhs[row] = -d[[i, p + c]];
+                for m in 0..p {
+                    sys[[row, m + p * c]] += d[[i, m]];
+                }
+                for m in 0..r {
+                    sys[[row, k + i + p * m]] -= d[[p + m, p + c]];
+                }
+            }
+        }
+    }
+    let x = solve_block_system(sys, rhs);
+    let rm = Matrix::from_shape_fn((p, r), |(i, c)| x[i + p * c]);
+    let lm = Matrix::from_shape_fn((p, r), |(i, c)| x[k + i + p * c]);
+
+    // [R; I] and [L; I] span the right and left deflating subspaces of the trailing block
+    let zm = complement_basis(rm.view());
+    let qm = complement_basis(lm.view());
+
+    let (sa, sb) = (qm.t().dot(&da).dot(&zm), qm.t().dot(&db).dot(&zm));
+    let scale = frob_norm(da.view()) + frob_norm(db.view());
+    let thresh = (10. * f64::EPSILON * scale).max(f64::MIN_POSITIVE);
+    if frob_norm(sa.slice(s![r..nd, 0..r])) + frob_norm(sb.slice(s![r..nd, 0..r])) > thresh {
+        return false;
+    }
+
+    for mut m in [a.view_mut(), b.view_mut()] {
+        let rows = qm.t().dot(&m.slice(s![j..j + nd, j..n]));
+        m.slice_mut(s![j..j + nd, j..n]).assign(&rows);
+        let cols = m.slice(s![0..j + nd, j..j + nd]).dot(&zm);
+        m.slice_mut(s![0..j + nd, j..j + nd]).assign(&cols);
+        m.slice_mut(s![j + r..j + nd, j..j + r]).fill(0.);
+    }
+    let cols = q.slice(s![0..n, j..j + nd]).dot(&qm);
+    q.slice_mut(s![0..n, j..j + nd]).assign(&cols);
+    let cols = z.slice(s![0..n, j..j + nd]).dot(&zm);
+    z.slice_mut(s![0..n, j..j + nd]).assign(&cols);
+
+    // restore the triangular B in the moved 2 by 2 blocks
+    for (i, sz) in [(j, r), (j + r, p)] {
+        if sz == 2 {
+            let rot = givens(b[[i, i]], b[[i + 1, i]]);
+            givens_rot_left(rot, a.slice_mut(s![i..i + 2, i..n]));
+            givens_rot_left(rot, b.slice_mut(s![i..i + 2, i..n]));
+            givens_rot_right(rot, q.slice_mut(s![0..n, i..i + 2]));
+            b[[i + 1, i]] = 0.;
+        }
+    }
+
+    true
+}
+
+pub fn reorder_generalized_schur(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    mut q: MatrixViewMut,
+    mut z: MatrixViewMut,
+    select: &[bool],
+) -> Option<usize> {
+    let n = a.shape()[0];
+    let (mut k, mut ks) = (0, 0);
+
+    while k < n {
+        let sz = block_size(a.view(), k);
+        if select[k] || (sz == 2 && select[k + 1]) {
+            let mut here = k;
+            while here > ks {
+                let prev = if here >= 2 && a[[here - 1, here - 2]] != 0. {
+                    2
+                } else {
+                    1
+                };
+
+                if !swap_generalized_schur_blocks(
+                    a.view_mut(),
+                    b.view_mut(),
+                    q.view_mut(),
+                    z.view_mut(),
+                    here - prev,
+                    prev,
+                    sz,
+                ) {
+                    return None;
+                }
+                here -= prev;
+            }
+            ks += sz;
+        }
+        k += sz;
+    }
+
+    Some(ks)
+}
+
+pub fn cluster_condition(t: MatrixView, m: usize) -> (f64, f64) {
+    let n = t.shape()[0];
+    if m == 0 || m == n {
+        return (1., frob_norm(t));
+    }
+
+    let t11 = t.slice(s![0..m, 0..m]);
+    let t22 = t.slice(s![m..n, m..n]);
+    let mut r = t.slice(s![0..m, m..n]).into_owned();
+    sylvester_quasi_triangular(t11, t22, r.view_mut(), -1.);
+
+    let rnorm = frob_norm(r.view());
+    let s = 1. / (1. + rnorm * rnorm).sqrt();
+    (s, sep_estimate(t11, t22, -1.))
+}
@@ -0,0 +1,62 @@
+use crate::implementation::least_squares::{apply_householder_qt, householder_qr};
+use crate::implementation::lu::{lu_decomposition, lu_solve};
+use crate::*;
+
+use ndarray::s;
+
+pub fn hamiltonian(a: MatrixView, g: MatrixView, q: MatrixView) -> Matrix {
+    let n = a.shape()[0];
+    let mut h = Matrix::zeros((2 * n, 2 * n));
+    h.slice_mut(s![0..n, 0..n]).assign(&a);
+    h.slice_mut(s![0..n, n..2 * n]).assign(&-&g);
+    h.slice_mut(s![n..2 * n, 0..n]).assign(&-&q);
+    h.slice_mut(s![n..2 * n, n..2 * n]).assign(&-&a.t());
+    h
+}
+
+// Extended symplectic pencil [[A, 0, B], [-Q, I, 0], [0, 0, R]] - l [[I, 0, 0], [0, A^T, 0], [0, -B^T, 0]],
+// compressed to 2n by 2n by the orthogonal complement of its last m columns so that A is never inverted
+pub fn symplectic_pencil(
+    a: MatrixView,
+    b: MatrixView,
+    q: MatrixView,
+    r: MatrixView,
+) -> (Matrix, Matrix) {
+    let (n, m) = (b.shape()[0], b.shape()[1]);
+    let k = 2 * n + m;
+    let mut h = Matrix::zeros((k, k));
+    h.slice_mut(s![0..n, 0..n]).assign(&a);
+    h.slice_mut(s![0..n, 2 * n..k]).assign(&b);
+    h.slice_mut(s![n..2 * n, 0..n]).assign(&-&q);
+    h.slice_mut(s![n..2 * n, n..2 * n]).assign(&Matrix::eye(n));
+    h.slice_mut(s![2 * n..k, 2 * n..k]).assign(&r);
+
+    let mut j = Matrix::zeros((k, 2 * n));
+    j.slice_mut(s![0..n, 0..n]).assign(&Matrix::eye(n));
+    j.slice_mut(s![n..2 * n, n..2 * n]).assign(&a.t());
+    j.slice_mut(s![2 * n..k, n..2 * n]).assign(&-&b.t());
+
+    let mut hb = h.slice(s![.., 2 * n..k]).into_owned();
+    let refls = householder_qr(hb.view_mut());
+    let mut h = h.slice(s![.., 0..2 * n]).into_owned();
+    apply_householder_qt(&refls, h.view_mut());
+    apply_householder_qt(&refls, j.view_mut());
+    (
+        h.slice(s![m..k, ..]).into_owned(),
+        j.slice(s![m..k, ..]).into_owned(),
+    )
+}
+
+// X = U21 U11^-1 from the leading n Schur vectors spanning the stable invariant subspace;
+// U has orthonormal columns, so pivots of U11 are compared against an absolute tolerance
+pub fn riccati_solution(u: MatrixView, tol: f64) -> Option<Matrix> {
+    let n = u.shape()[0] / 2;
+    let mut lu = u.slice(s![0..n, 0..n]).t().into_owned();
+    let (perm, _) = lu_decomposition(lu.view_mut());
+    if lu.diag().iter().any(|x| x.abs() <= tol) {
+        return None;
+    }
+
+    let xt = lu_solve(lu.view(), &perm, u.slice(s![n..2 * n, 0..n]).t());
+    Some((&xt + &xt.t()) / 2.)
+}
@@ -0,0 +1,198 @@
+use crate::implementation::checks::frob_norm;
+use crate::implementation::common::*;
+use crate::implementation::reorder::reorder_schur;
+use crate::implementation::sylvester::sylvester_quasi_triangular;
+use crate::*;
+
+use ndarray::s;
+
+const TAYLOR_TERMS: usize = 250;
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut r = i;
+    while parent[r] != r {
+        r = parent[r];
+    }
+    parent[i] = r;
+    r
+}
+
+fn eigenvalue_clusters(t: MatrixView, delta: f64) -> Vec<Vec<Complex>> {
+    let atoms = schur_blocks(t);
+    let eigs: Vec<Vec<Complex>> = atoms
+        .iter()
+        .map(|&(i, sz)| extract_eigenvalues(t.slice(s![i..i + sz, i..i + sz])))
+        .collect();
+
+    let mut parent: Vec<usize> = (0..atoms.len()).collect();
+    for a in 0..atoms.len() {
+        for b in 0..a {
+            let close = eigs[a]
+                .iter()
+                .any(|x| eigs[b].iter().any(|y| (x - y).norm() <= delta));
+            if close {
+                let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+                parent[ra.max(rb)] = ra.min(rb);
+            }
+        }
+    }
+
+    let mut labels = Vec::new();
+    let mut clusters: Vec<Vec<Complex>> = Vec::new();
+    for (a, e) in eigs.into_iter().enumerate() {
+        let r = find(&mut parent, a);
+        match labels.iter().position(|x| *x == r) {
+            Some(c) => clusters[c].extend(e),
+            None => {
+                labels.push(r);
+                clusters.push(e);
+            }
+        }
+    }
+    clusters
+}
+
+fn cluster_of(x: Complex, clusters: &[Vec<Complex>]) -> usize {
+    // clusters are more than delta apart, so the nearest eigenvalue identifies the cluster
+    // even after reordering has perturbed the diagonal blocks
+    let dist = |c: &Vec<Complex>| {
+        c.iter()
+            .map(|y| (x - y).norm())
+            .fold(f64::INFINITY, f64::min)
+    };
+    (0..clusters.len())
+        .min_by(|&a, &b| dist(&clusters[a]).partial_cmp(&dist(&clusters[b])).unwrap())
+        .unwrap()
+}
+
+// f(T) = alpha I + beta T for a 2 by 2 block with eigenvalues l, conj(l), matching f at both
+pub fn complex_pair_function(t: MatrixView, f: impl Fn(Complex) -> Complex) -> Matrix {
+    let l = extract_eigenvalues(t)[0];
+    let fl = f(l);
+    let beta = fl.im / l.im;
+    let alpha = fl.re - beta * l.re;
+    beta * &t + alpha * Matrix::eye(2)
+}
+
+fn block_function(t: MatrixView, f: &dyn Fn(Complex, usize) -> Complex) -> Option<Matrix> {
+    let n = t.shape()[0];
+    let eigs = extract_eigenvalues(t);
+
+    if n == 1 {
+        return Some(Matrix::from_elem((1, 1), f(eigs[0], 0).re));
+    }
+
+    if n == 2 && eigs[0].im != 0. {
+        return Some(complex_pair_function(t, |z| f(z, 0)));
+    }
+
+    let sigma = eigs.iter().map(|x| x.re).sum::<f64>() / n as f64;
+    let m = &t - &(sigma * Matrix::eye(n));
+
+    // mu = |y|_inf, where (I - |N|) y = e and N is the strictly upper triangular part of M
+    let mut y = vec![1.; n];
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            y[i] += m[[i, j]].abs() * y[j];
+        }
+    }
+    let mu = y.iter().cloned().fold(0., f64::max);
+
+    // p = M^k / k!, updated in place so that k! is never formed
+    let mut p = Matrix::eye(n);
+    let mut fm = Matrix::zeros((n, n));
+
+    for k in 0..TAYLOR_TERMS {
+        if k > 0 {
+            p = p.dot(&m) / k as f64;
+        }
+        let term = f(Complex::from(sigma), k).re * &p;
+        fm += &term;
+
+        let fnorm = frob_norm(fm.view());
+        if frob_norm(term.view()) <= f64::EPSILON * fnorm {
+            // Davies-Higham bound on the remainder: mu max_r w_{k+r+1} / r! |P|,
+            // where w_j is the largest |f^(j)| over the eigenvalues of the block
+            let mut delta: f64 = 0.;
+            let mut rf = 1.;
+            for r in 0..n {
+                if r > 0 {
+                    rf /= r as f64;
+                }
+                let w = eigs
+                    .iter()
+                    .map(|x| f(*x, k + r + 1).norm())
+                    .fold(0., f64::max);
+                delta = delta.max(w * rf);
+            }
+
+            if mu * delta * frob_norm(p.view()) <= f64::EPSILON * fnorm {
+                return Some(fm);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn schur_parlett(
+    mut t: MatrixViewMut,
+    mut u: MatrixViewMut,
+    f: &dyn Fn(Complex, usize) -> Complex,
+    delta: f64,
+) -> Option<Matrix> {
+    let n = t.shape()[0];
+    let eigs = eigenvalue_clusters(t.view(), delta);
+    let clusters = eigs.len();
+
+    let mut bounds = vec![0];
+    for c in 0..clusters {
+        // atoms move with every reordering, so they are located in the current t
+        let mut select = vec![false; n];
+        for (i, sz) in schur_blocks(t.view()) {
+            let x = extract_eigenvalues(t.slice(s![i..i + sz, i..i + sz]))[0];
+            if cluster_of(x, &eigs) <= c {
+                select[i..i + sz].iter_mut().for_each(|x| *x = true);
+            }
+        }
+        bounds.push(reorder_schur(t.view_mut(), u.view_mut(), &select)?);
+    }
+
+    let mut ft = Matrix::zeros((n, n));
+    for c in 0..clusters {
+        let (i0, i1) = (bounds[c], bounds[c + 1]);
+        let fb = block_function(t.slice(s![i0..i1, i0..i1]), f)?;
+        ft.slice_mut(s![i0..i1, i0..i1]).assign(&fb);
+    }
+
+    for d in 1..clusters {
+        for c in 0..clusters - d {
+            let (i0, i1) = (bounds[c], bounds[c + 1]);
+            let (j0, j1) = (bounds[c + d], bounds[c + d + 1]);
+
+            let mut rhs = ft
+                .slice(s![i0..i1, i0..i1])
+                .dot(&t.slice(s![i0..i1, j0..j1]))
+                - t.slice(s![i0..i1, j0..j1])
+                    .dot(&ft.slice(s![j0..j1, j0..j1]));
+            if i1 < j0 {
+                rhs += &ft
+                    .slice(s![i0..i1, i1..j0])
+                    .dot(&t.slice(s![i1..j0, j0..j1]));
+                rhs -= &t
+                    .slice(s![i0..i1, i1..j0])
+                    .dot(&ft.slice(s![i1..j0, j0..j1]));
+            }
+
+            sylvester_quasi_triangular(
+                t.slice(s![i0..i1, i0..i1]),
+                t.slice(s![j0..j1, j0..j1]),
+                rhs.view_mut(),
+                -1.,
+            );
+            ft.slice_mut(s![i0..i1, j0..j1]).assign(&rhs);
+        }
+    }
+
+    Some(u.dot(&ft).dot(&u.t()))
+}
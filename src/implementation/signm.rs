@@ -0,0 +1,50 @@
+use crate::implementation::expm::norm_1;
+use crate::implementation::lu::{lu_decomposition, lu_singular, lu_solve};
+use crate::*;
+
+// Determinant scaling is switched off once the iterates are this close, to keep quadratic convergence
+const SCALING_CUTOFF: f64 = 1e-2;
+
+pub fn sign_newton(
+    a: MatrixView,
+    mut lu: Matrix,
+    mut perm: Vec<usize>,
+    eps: f64,
+    iterations: usize,
+) -> Option<Matrix> {
+    // lu and perm hold the LU decomposition of the current iterate, starting with a
+    let n = a.shape()[0];
+    let mut x = a.into_owned();
+    let mut scale = true;
+
+    for _ in 0..iterations {
+        let inv = lu_solve(lu.view(), &perm, Matrix::eye(n).view());
+
+        let mu = if scale {
+            let log_det = lu.diag().iter().map(|d| d.abs().ln()).sum::<f64>();
+            (-log_det / n as f64).exp()
+        } else {
+            1.
+        };
+
+        let next = (mu * &x + &inv / mu) / 2.;
+        let diff = norm_1((&next - &x).view());
+        let nrm = norm_1(next.view());
+        x = next;
+
+        if diff <= eps * nrm {
+            return Some(x);
+        }
+        if diff <= SCALING_CUTOFF * nrm {
+            scale = false;
+        }
+
+        lu = x.clone();
+        perm = lu_decomposition(lu.view_mut()).0;
+        if lu_singular(lu.view()) {
+            return None;
+        }
+    }
+
+    None
+}
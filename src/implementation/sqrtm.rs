@@ -0,0 +1,50 @@
+use crate::implementation::checks::frob_norm;
+use crate::implementation::common::*;
+use crate::implementation::schur_parlett::complex_pair_function;
+use crate::implementation::sylvester::sylvester_quasi_triangular;
+use crate::*;
+
+use ndarray::s;
+
+// Real eigenvalues at most eps |T| count as zero
+pub fn principal_branch_exists(t: MatrixView, eps: f64) -> bool {
+    let tol = eps * frob_norm(t);
+    extract_eigenvalues(t)
+        .iter()
+        .all(|l| l.im != 0. || l.re > tol)
+}
+
+pub fn sqrtm_quasi_triangular(t: MatrixView) -> Matrix {
+    let n = t.shape()[0];
+    let blocks = schur_blocks(t);
+    let mut r = Matrix::zeros((n, n));
+
+    for (bj, &(j, q)) in blocks.iter().enumerate() {
+        let tjj = t.slice(s![j..j + q, j..j + q]);
+        if q == 1 {
+            r[[j, j]] = tjj[[0, 0]].sqrt();
+        } else {
+            let rjj = complex_pair_function(tjj, |z| z.sqrt());
+            r.slice_mut(s![j..j + q, j..j + q]).assign(&rjj);
+        }
+
+        for &(i, p) in blocks[..bj].iter().rev() {
+            // R_ii R_ij + R_ij R_jj = T_ij - sum_k R_ik R_kj
+            let mut rhs = t.slice(s![i..i + p, j..j + q]).into_owned();
+            if i + p < j {
+                rhs -= &r
+                    .slice(s![i..i + p, i + p..j])
+                    .dot(&r.slice(s![i + p..j, j..j + q]));
+            }
+            sylvester_quasi_triangular(
+                r.slice(s![i..i + p, i..i + p]),
+                r.slice(s![j..j + q, j..j + q]),
+                rhs.view_mut(),
+                1.,
+            );
+            r.slice_mut(s![i..i + p, j..j + q]).assign(&rhs);
+        }
+    }
+
+    r
+}
@@ -1,9 +1,12 @@
 use crate::implementation::common::*;
+use crate::implementation::dqds::dqds;
 use crate::implementation::hessenberg::hessenberg_form;
+use crate::implementation::householder::*;
 use crate::implementation::qr_symmetric::*;
 use crate::*;
 
-use ndarray::Axis;
+use ndarray::{s, Axis};
+use std::cmp::Ordering;
 
 pub fn svd(m: MatrixView, opts: &QROptions) -> (Matrix, Vector, Matrix) {
     let mut s = m.dot(&m.t());
@@ -11,10 +14,12 @@ pub fn svd(m: MatrixView, opts: &QROptions) -> (Matrix, Vector, Matrix) {
     hessenberg_form(s.view_mut(), u.view_mut(), opts);
     qr_algorithm_symmetric(s.view_mut(), u.view_mut(), opts);
 
-    let mut z: Vector = s.diag().into_iter().map(|x| x.sqrt()).collect();
+    let mut z: Vector = s.diag().into_iter().map(|x| x.max(0.).sqrt()).collect();
     sort_diagonal_values(z.view_mut(), u.view_mut());
 
+    let cutoff = opts.eps * z.iter().cloned().fold(0., f64::max);
     let mut vs: Vec<Vector> = (0..z.shape()[0])
+        .take_while(|i| z[*i] > cutoff)
         .map(|i| m.t().dot(&u.column(i)) / z[i])
         .collect();
 
@@ -25,7 +30,69 @@ pub fn svd(m: MatrixView, opts: &QROptions) -> (Matrix, Vector, Matrix) {
         .collect();
 
     vs.append(&mut eyes);
-    orthonormalize(vs.as_mut_slice());
-    let vt: Matrix = stack_owned(Axis(0), &vs[0..m.shape()[1]]);
+    let vs = orthonormal_basis(vs.as_slice(), m.shape()[1], opts.eps);
+    let vt: Matrix = stack_owned(Axis(0), vs.as_slice());
     (u, z, vt)
 }
+
+pub fn polar_decomposition(m: MatrixView, opts: &QROptions) -> (Matrix, Matrix) {
+    let k = m.shape()[0].min(m.shape()[1]);
+    let (u, z, vt) = svd(m, opts);
+    let (u, z, vt) = (
+        u.slice(s![.., 0..k]),
+        z.slice(s![0..k]),
+        vt.slice(s![0..k, ..]),
+    );
+
+    let up = u.dot(&vt);
+    let h = vt.t().dot(&(&vt * &z.insert_axis(Axis(1))));
+    (up, h)
+}
+
+// Householder reduction A = Q B P^T of an m by n matrix with m >= n to the upper bidiagonal form (Golub, Kahan);
+// outputs the diagonal and the superdiagonal of B
+pub fn bidiagonal_form(m: MatrixView) -> (Vector, Vector) {
+    let n = m.shape()[1];
+    let mut b = m.into_owned();
+
+    for k in 0..n {
+        let v = householder_vec(b.slice(s![k.., k]));
+        householder_refl_left(v.view(), b.slice_mut(s![k.., k..]));
+        if k + 2 < n {
+            let v = householder_vec(b.slice(s![k, k + 1..]));
+            householder_refl_right(v.view(), b.slice_mut(s![k.., k + 1..]));
+        }
+    }
+
+    let d = b.diag().into_owned();
+    let e = (1..n).map(|k| b[[k - 1, k]]).collect();
+    (d, e)
+}
+
+// min(m, n) singular values in the descending order, computed from the bidiagonal form by dqds
+// to high relative accuracy with respect to the bidiagonal matrix; None if dqds did not converge
+pub fn singular_values(m: MatrixView) -> Option<Vector> {
+    let (rows, cols) = (m.shape()[0], m.shape()[1]);
+    if rows < cols {
+        return singular_values(m.t());
+    }
+
+    let (d, e) = bidiagonal_form(m);
+    // scale before squaring so that the qd array neither overflows nor underflows needlessly
+    let scale = d
+        .iter()
+        .chain(e.iter())
+        .fold(0., |acc: f64, x| acc.max(x.abs()));
+    if scale == 0. {
+        return Some(Vector::zeros(cols));
+    }
+
+    let mut q: Vec<f64> = d.iter().map(|x| (x / scale) * (x / scale)).collect();
+    let mut qe: Vec<f64> = e.iter().map(|x| (x / scale) * (x / scale)).collect();
+    if !dqds(&mut q, &mut qe) {
+        return None;
+    }
+    q.sort_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    Some(q.iter().map(|x| scale * x.max(0.).sqrt()).collect())
+}
@@ -0,0 +1,133 @@
+use crate::implementation::blocks::*;
+use crate::implementation::checks::frob_norm;
+use crate::implementation::common::*;
+use crate::*;
+
+use ndarray::s;
+
+pub fn sylvester_quasi_triangular(a: MatrixView, b: MatrixView, mut c: MatrixViewMut, sign: f64) {
+    let a_blocks = schur_blocks(a);
+    let b_blocks = schur_blocks(b);
+    let m = a.shape()[0];
+
+    for &(l, q) in b_blocks.iter() {
+        for &(k, p) in a_blocks.iter().rev() {
+            let mut rhs = c.slice(s![k..k + p, l..l + q]).into_owned();
+            if k + p < m {
+                rhs -= &a
+                    .slice(s![k..k + p, k + p..m])
+                    .dot(&c.slice(s![k + p..m, l..l + q]));
+            }
+            if l > 0 {
+                rhs -= &(sign
+                    * &c.slice(s![k..k + p, 0..l])
+                        .dot(&b.slice(s![0..l, l..l + q])));
+            }
+
+            let mut sys = Matrix::zeros((p * q, p * q));
+            let mut r = Vector::zeros(p * q);
+            for j in 0..q {
+                for i in 0..p {
+                    r[i + p * j] = rhs[[i, j]];
+                    for i2 in 0..p {
+                        sys[[i + p * j, i2 + p * j]] += a[[k + i, k + i2]];
+                    }
+                    for j2 in 0..q {
+                        sys[[i + p * j, i + p * j2]] += sign * b[[l + j2, l + j]];
+                    }
+                }
+            }
+
+            let x = solve_block_system(sys, r);
+            for j in 0..q {
+                for i in 0..p {
+                    c[[k + i, l + j]] = x[i + p * j];
+                }
+            }
+        }
+    }
+}
+
+pub fn stein_quasi_triangular(a: MatrixView, b: MatrixView, mut c: MatrixViewMut) {
+    let a_blocks = schur_blocks(a);
+    let b_blocks = schur_blocks(b);
+    let m = a.shape()[0];
+
+    for &(l, q) in b_blocks.iter() {
+        // Column block l of X B, from the solved column blocks j < l; rows are completed as X_kl is found
+        let mut xb = c.slice(s![.., 0..l]).dot(&b.slice(s![0..l, l..l + q]));
+
+        for &(k, p) in a_blocks.iter().rev() {
+            // A_kk X_kl B_ll - X_kl = C_kl - A_kk (X B)_kl - sum_{i > k} A_ki (X B)_il
+            let rhs = &c.slice(s![k..k + p, l..l + q])
+                - &a.slice(s![k..k + p, k..m]).dot(&xb.slice(s![k..m, ..]));
+
+            let mut sys = Matrix::zeros((p * q, p * q));
+            let mut r = Vector::zeros(p * q);
+            for j in 0..q {
+                for i in 0..p {
+                    r[i + p * j] = rhs[[i, j]];
+                    sys[[i + p * j, i + p * j]] -= 1.;
+                    for j2 in 0..q {
+                        for i2 in 0..p {
+                            sys[[i + p * j, i2 + p * j2]] +=
+                                a[[k + i, k + i2]] * b[[l + j2, l + j]];
+                        }
+                    }
+                }
+            }
+
+            let y = solve_block_system(sys, r);
+            for j in 0..q {
+                for i in 0..p {
+                    c[[k + i, l + j]] = y[i + p * j];
+                }
+            }
+
+            let xkl = c
+                .slice(s![k..k + p, l..l + q])
+                .dot(&b.slice(s![l..l + q, l..l + q]));
+            let mut row = xb.slice_mut(s![k..k + p, ..]);
+            row += &xkl;
+        }
+    }
+}
+
+pub fn sep_estimate(a: MatrixView, b: MatrixView, sign: f64) -> f64 {
+    let (m, n) = (a.shape()[0], b.shape()[0]);
+    if m == 0 || n == 0 {
+        return f64::INFINITY;
+    }
+
+    let mut x = Matrix::from_elem((m, n), 1. / ((m * n) as f64).sqrt());
+    let mut inv_norm = 0.;
+    for _ in 0..5 {
+        let mut y = x.clone();
+        sylvester_quasi_triangular(a, b, y.view_mut(), sign);
+        inv_norm = frob_norm(y.view());
+
+        // Adjoint solve: A^T Z + sign Z B^T = Y, transposed to B W + sign W A = sign Y^T
+        let mut w = sign * &y.t();
+        sylvester_quasi_triangular(b, a, w.view_mut(), sign);
+        let nw = frob_norm(w.view());
+        if nw == 0. || !nw.is_finite() {
+            break;
+        }
+        x = w.t().to_owned() / nw;
+    }
+
+    1. / inv_norm
+}
+
+pub fn bartels_stewart(
+    s: MatrixView,
+    u: MatrixView,
+    t: MatrixView,
+    v: MatrixView,
+    c: MatrixView,
+    sign: f64,
+) -> Matrix {
+    let mut x = u.t().dot(&c).dot(&v);
+    sylvester_quasi_triangular(s, t, x.view_mut(), sign);
+    u.dot(&x).dot(&v.t())
+}
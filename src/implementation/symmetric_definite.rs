@@ -0,0 +1,14 @@
+use crate::implementation::triangular::*;
+use crate::*;
+
+pub fn symmetric_definite_reduction(k: MatrixView, l: MatrixView) -> Matrix {
+    let mut c = k.into_owned();
+    solve_lower_triangular(l, c.view_mut());
+    let mut c = c.t().into_owned();
+    solve_lower_triangular(l, c.view_mut());
+    0.5 * (&c + &c.t())
+}
+
+pub fn symmetric_definite_back_transform(l: MatrixView, u: MatrixViewMut) {
+    solve_upper_triangular(l.t(), u);
+}
@@ -0,0 +1,40 @@
+use crate::*;
+
+pub fn solve_lower_triangular(l: MatrixView, mut b: MatrixViewMut) {
+    let n = l.shape()[0];
+    for mut col in b.gencolumns_mut() {
+        for i in 0..n {
+            let mut x = col[i];
+            for k in 0..i {
+                x -= l[[i, k]] * col[k];
+            }
+            col[i] = x / l[[i, i]];
+        }
+    }
+}
+
+pub fn solve_upper_triangular(r: MatrixView, mut b: MatrixViewMut) {
+    let n = r.shape()[0];
+    for mut col in b.gencolumns_mut() {
+        for i in (0..n).rev() {
+            let mut x = col[i];
+            for k in i + 1..n {
+                x -= r[[i, k]] * col[k];
+            }
+            col[i] = x / r[[i, i]];
+        }
+    }
+}
+
+pub fn solve_unit_lower_triangular(l: MatrixView, mut b: MatrixViewMut) {
+    let n = l.shape()[0];
+    for mut col in b.gencolumns_mut() {
+        for i in 0..n {
+            let mut x = col[i];
+            for k in 0..i {
+                x -= l[[i, k]] * col[k];
+            }
+            col[i] = x;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::implementation::bisection::*;
+use crate::implementation::checks::{diff_symm, finite_entries};
+use crate::implementation::hessenberg::hessenberg_form;
+use crate::*;
+
+#[inline]
+fn symmetric_tridiagonal(
+    m: MatrixView,
+    accumulate: bool,
+    opts: &QROptions,
+) -> Result<(Vector, Vector, Matrix)> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+
+        let diff = diff_symm(m);
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::NotSymmetric);
+        }
+    }
+
+    let n = m.shape()[0];
+    let mut t = m.into_owned();
+    let mut u = Matrix::eye(n);
+    let hess_opts = QROptions {
+        accumulate_sim_transforms: accumulate,
+        ..opts.clone()
+    };
+    hessenberg_form(t.view_mut(), u.view_mut(), &hess_opts);
+
+    let d = t.diag().into_owned();
+    let e = (1..n).map(|k| t[[k, k - 1]]).collect();
+    Ok((d, e, u))
+}
+
+#[inline]
+fn selected_eigenvalues(d: VectorView, e: VectorView, range: EigenvalueRange) -> Result<Vector> {
+    let n = d.shape()[0];
+    let (k, l) = match range {
+        EigenvalueRange::Interval(a, b) => {
+            if a.is_nan() || b.is_nan() || a > b {
+                return Err(QRError::InvalidParameter);
+            }
+            (sturm_count(d, e, a), sturm_count(d, e, b))
+        }
+        EigenvalueRange::Indices(k, l) => {
+            if k > l || l > n {
+                return Err(QRError::InvalidParameter);
+            }
+            (k, l)
+        }
+    };
+
+    Ok((k..l).map(|i| bisection(d, e, i)).collect())
+}
+
+/// Computes selected eigenvalues of a symmetric matrix, accepts options
+///
+/// Outputs the eigenvalues in `range` sorted in the ascending order.
+///
+/// Reduces the matrix to the tridiagonal form with `hessenberg_form` and finds each selected eigenvalue by bisection,
+/// counting the eigenvalues below a point with the Sturm sequence of the tridiagonal matrix. Eigenvalues are computed to full precision.
+/// `opts.algorithm` and `opts.accumulate_sim_transforms` are ignored.
+///
+/// Accepts any symmetric matrix with finite entries.
+/// Fails with `InvalidParameter` if `range` is an empty interval `a > b` or its indices are out of bounds.
+/// Performs O(n^3) operations for the reduction and O(n) operations per bisection step for each selected eigenvalue.
+pub fn symmetric_eigenvalues_range_opts(
+    m: MatrixView,
+    range: EigenvalueRange,
+    opts: &QROptions,
+) -> Result<Vector> {
+    let (d, e, _) = symmetric_tridiagonal(m, false, opts)?;
+    selected_eigenvalues(d.view(), e.view(), range)
+}
+
+/// Computes selected eigenvalues of a symmetric matrix
+///
+/// Uses the default symmetric options.
+/// See `symmetric_eigenvalues_range_opts`.
+pub fn symmetric_eigenvalues_range(m: MatrixView, range: EigenvalueRange) -> Result<Vector> {
+    symmetric_eigenvalues_range_opts(m, range, &SYMMETRIC_OPTS)
+}
+
+/// Computes selected eigenpairs of a symmetric matrix, accepts options
+///
+/// Outputs `(z, X)` with the eigenvalues `z` in `range` sorted in the ascending order and the corresponding orthonormal eigenvectors in the columns of `X`.
+///
+/// Eigenvalues are found as in `symmetric_eigenvalues_range_opts`. Eigenvectors of the tridiagonal matrix are computed by inverse iteration
+/// and reorthogonalized within clusters of close eigenvalues, then transformed back with the Hessenberg reduction.
+/// Fails with `ConvergenceFailed` if inverse iteration does not reach the residual `opts.eps * |A|` in a few steps.
+///
+/// Accepts any symmetric matrix with finite entries.
+/// Performs O(n^3) operations for the reduction and O(n^2) operations per selected eigenpair.
+pub fn symmetric_eigen_range_opts(
+    m: MatrixView,
+    range: EigenvalueRange,
+    opts: &QROptions,
+) -> Result<(Vector, Matrix)> {
+    let (d, e, u) = symmetric_tridiagonal(m, true, opts)?;
+    let z = selected_eigenvalues(d.view(), e.view(), range)?;
+    let x =
+        inverse_iteration(d.view(), e.view(), z.view(), opts).ok_or(QRError::ConvergenceFailed)?;
+    Ok((z, u.dot(&x)))
+}
+
+/// Computes selected eigenpairs of a symmetric matrix
+///
+/// Outputs `(z, X)`.
+/// Uses the default symmetric options.
+/// See `symmetric_eigen_range_opts`.
+pub fn symmetric_eigen_range(m: MatrixView, range: EigenvalueRange) -> Result<(Vector, Matrix)> {
+    symmetric_eigen_range_opts(m, range, &SYMMETRIC_OPTS)
+}
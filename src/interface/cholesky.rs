@@ -0,0 +1,118 @@
+use crate::implementation::checks::{diff_symm, finite_entries};
+use crate::implementation::triangular::*;
+use crate::*;
+
+/// Computes the Cholesky decomposition of a matrix, accepts options
+///
+/// Outputs the lower triangular factor `L`.
+///
+/// The Cholesky decomposition of a symmetric positive definite matrix is a decomposition `A = L L^T`
+/// where `L` is lower triangular with positive diagonal entries.
+/// Only the lower triangle of `A` is referenced.
+///
+/// Accepts any symmetric matrix with finite entries.
+/// Fails with `NotPositiveDefinite(k)` if the `k`-th pivot is not positive, which happens exactly when `A` is not positive definite.
+/// Performs O(n^3) operations.
+pub fn cholesky_opts(m: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+
+        let diff = diff_symm(m);
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::NotSymmetric);
+        }
+    }
+
+    let mut l = m.into_owned();
+    crate::implementation::cholesky::cholesky(l.view_mut())
+        .map_err(QRError::NotPositiveDefinite)?;
+    Ok(l)
+}
+
+/// Computes the Cholesky decomposition of a matrix
+///
+/// Outputs `L`.
+/// Uses default options.
+/// See `cholesky_opts`.
+pub fn cholesky(m: MatrixView) -> Result<Matrix> {
+    cholesky_opts(m, &DEFAULT_OPTS)
+}
+
+/// Solves a triangular linear system, accepts options
+///
+/// Outputs `X` such that `T X = B`, where `T` is lower triangular if `lower == true` and upper triangular otherwise.
+/// Columns of `B` are treated as separate right-hand sides.
+/// Entries of `T` outside of the referenced triangle are ignored.
+///
+/// Accepts a triangular matrix with finite entries and non-zero diagonal.
+/// Fails with `Singular` if the diagonal of `T` contains zeros.
+/// Performs O(n^2) operations per right-hand side.
+pub fn triangular_solve_opts(
+    t: MatrixView,
+    b: MatrixView,
+    lower: bool,
+    opts: &QROptions,
+) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        if !finite_entries(t) || !finite_entries(b) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !t.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if t.shape()[0] != b.shape()[0] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    if t.diag().iter().any(|x| *x == 0.) {
+        return Err(QRError::Singular);
+    }
+
+    let mut x = b.into_owned();
+    if lower {
+        solve_lower_triangular(t, x.view_mut());
+    } else {
+        solve_upper_triangular(t, x.view_mut());
+    }
+    Ok(x)
+}
+
+/// Solves a triangular linear system
+///
+/// Outputs `X` such that `T X = B`.
+/// Uses default options.
+/// See `triangular_solve_opts`.
+pub fn triangular_solve(t: MatrixView, b: MatrixView, lower: bool) -> Result<Matrix> {
+    triangular_solve_opts(t, b, lower, &DEFAULT_OPTS)
+}
+
+/// Solves a symmetric positive definite linear system using its Cholesky decomposition, accepts options
+///
+/// Outputs `X` such that `A X = B`, where `A = L L^T` and `L` is the factor returned by `cholesky_opts`.
+/// Columns of `B` are treated as separate right-hand sides.
+///
+/// Solves `L Y = B` and `L^T X = Y` by forward and back substitution.
+/// Performs O(n^2) operations per right-hand side.
+pub fn cholesky_solve_opts(l: MatrixView, b: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    let mut x = triangular_solve_opts(l, b, true, opts)?;
+    solve_upper_triangular(l.t(), x.view_mut());
+    Ok(x)
+}
+
+/// Solves a symmetric positive definite linear system using its Cholesky decomposition
+///
+/// Outputs `X` such that `A X = B`.
+/// Uses default options.
+/// See `cholesky_solve_opts`.
+pub fn cholesky_solve(l: MatrixView, b: MatrixView) -> Result<Matrix> {
+    cholesky_solve_opts(l, b, &DEFAULT_OPTS)
+}
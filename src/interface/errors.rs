@@ -6,12 +6,28 @@ use std::fmt;
 /// - NotSquare --- supplied matrix is not square.
 /// - NotSymmetric --- symmetric algorithm used, but the matrix is not symmetric.
 /// - ConvergenceFailed --- algorithm failed to converge.
+/// - DimensionMismatch --- supplied matrices or vectors have incompatible dimensions.
+/// - ReorderingFailed --- Schur form reordering was rejected because the swapped blocks have too close eigenvalues.
+/// - Singular --- supplied matrix is (numerically) singular.
+/// - NotPositiveDefinite(k) --- supplied matrix is not positive definite; the Cholesky decomposition broke down at pivot `k`.
+/// - NoPrincipalBranch --- supplied matrix has eigenvalues on the closed negative real axis, so the principal matrix function is undefined.
+/// - NotStable --- supplied matrix is not stable (has eigenvalues outside the open left half-plane or the open unit disk).
+/// - InvalidParameter --- supplied scalar parameter is outside of its admissible range.
+/// - NoStabilizingSolution --- the Riccati equation has no stabilizing solution (the system is not stabilizable or detectable, or has modes on the stability boundary).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum QRError {
     NotFinite,
     NotSquare,
     NotSymmetric,
     ConvergenceFailed,
+    DimensionMismatch,
+    ReorderingFailed,
+    Singular,
+    NotPositiveDefinite(usize),
+    NoPrincipalBranch,
+    NotStable,
+    NoStabilizingSolution,
+    InvalidParameter,
 }
 
 impl fmt::Display for QRError {
@@ -21,6 +37,23 @@ impl fmt::Display for QRError {
             QRError::NotSquare => write!(f, "supplied matrix is not square"),
             QRError::NotSymmetric => write!(f, "supplied matrix is not symmetric"),
             QRError::ConvergenceFailed => write!(f, "algorithm failed to converge"),
+            QRError::DimensionMismatch => {
+                write!(f, "supplied matrices have incompatible dimensions")
+            }
+            QRError::ReorderingFailed => write!(f, "schur form reordering failed"),
+            QRError::Singular => write!(f, "supplied matrix is singular"),
+            QRError::NotPositiveDefinite(k) => {
+                write!(f, "supplied matrix is not positive definite (pivot {})", k)
+            }
+            QRError::NoPrincipalBranch => {
+                write!(
+                    f,
+                    "supplied matrix has eigenvalues on the closed negative real axis"
+                )
+            }
+            QRError::NotStable => write!(f, "supplied matrix is not stable"),
+            QRError::NoStabilizingSolution => write!(f, "no stabilizing solution exists"),
+            QRError::InvalidParameter => write!(f, "supplied parameter is out of range"),
         }
     }
 }
@@ -0,0 +1,61 @@
+use crate::implementation::checks::finite_entries;
+use crate::implementation::reorder::{cluster_condition, reorder_schur};
+use crate::*;
+
+use ndarray::s;
+
+/// Computes an invariant subspace of a matrix with condition estimates, accepts options
+///
+/// Outputs `(X, s, sep)` where columns of `X` are an orthonormal basis of the invariant subspace,
+/// `s` is the reciprocal condition number of the average of the selected eigenvalues
+/// and `sep` is an estimate of the reciprocal condition number of the subspace.
+///
+/// Subspace `X` is invariant under `A` if `AX` lies in `X`.
+/// The Schur form is reordered so that the selected eigenvalues lead its diagonal (see `reorder_schur_inplace_opts`),
+/// then `X` consists of the leading columns of the reordered `U`.
+/// With `T = [[T11, T12], [0, T22]]`, `s = 1 / sqrt(1 + |R|^2)` where `T11 R - R T22 = T12`,
+/// and `sep` estimates the smallest singular value of `R -> T11 R - R T22`.
+/// Small values of `s` or `sep` indicate an ill-conditioned cluster or subspace.
+///
+/// Accepts a pair `(T, U)` as returned by `schur_form_opts`.
+/// Performs O(n^3) operations.
+pub fn invariant_subspace_opts(
+    t: MatrixView,
+    u: MatrixView,
+    select: &[bool],
+    opts: &QROptions,
+) -> Result<(Matrix, f64, f64)> {
+    if opts.do_safety_checks {
+        if !finite_entries(t) || !finite_entries(u) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !t.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if u.shape() != t.shape() || select.len() != t.shape()[0] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    let mut t = t.into_owned();
+    let mut u = u.into_owned();
+    let m = reorder_schur(t.view_mut(), u.view_mut(), select).ok_or(QRError::ReorderingFailed)?;
+    let (s, sep) = cluster_condition(t.view(), m);
+
+    Ok((u.slice(s![.., 0..m]).into_owned(), s, sep))
+}
+
+/// Computes an invariant subspace of a matrix with condition estimates
+///
+/// Outputs `(X, s, sep)`.
+/// Uses default options.
+/// See `invariant_subspace_opts`.
+pub fn invariant_subspace(
+    t: MatrixView,
+    u: MatrixView,
+    select: &[bool],
+) -> Result<(Matrix, f64, f64)> {
+    invariant_subspace_opts(t, u, select, &DEFAULT_OPTS)
+}
@@ -0,0 +1,97 @@
+use crate::implementation::checks::{diff_symm, finite_entries};
+use crate::implementation::common::sort_diagonal_values;
+use crate::*;
+
+/// Computes the eigendecomposition of a symmetric matrix with the Jacobi algorithm, accepts options
+///
+/// Outputs `(z, U, info)` with eigenvalues `z` sorted in the descending order, the corresponding orthonormal eigenvectors in the columns of `U`
+/// and the convergence report `info`.
+///
+/// Runs cyclic sweeps of Jacobi rotations directly on the matrix (see `QRAlgorithm::Jacobi`), skipping the pairs with `|a_pq| <= opts.eps sqrt(|a_pp a_qq|)`,
+/// until a sweep performs no rotation. Unlike the QR algorithm after the tridiagonal reduction, the eigenvalues of a positive definite `A = D H D`
+/// with diagonal `D` are computed with relative errors bounded in terms of the condition number of `H` rather than of `A`,
+/// so small eigenvalues of graded or badly scaled matrices keep their accuracy.
+/// `opts.algorithm` is ignored, `opts.iterations` limits the number of sweeps and transformations are always accumulated.
+///
+/// Accepts any symmetric matrix with finite entries.
+/// Fails with `ConvergenceFailed` if the sweeps are exhausted.
+/// Performs O(n^3) operations per sweep; a few sweeps are usually enough.
+pub fn jacobi_eigen_opts(m: MatrixView, opts: &QROptions) -> Result<(Vector, Matrix, JacobiInfo)> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+
+        let diff = diff_symm(m);
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::NotSymmetric);
+        }
+    }
+
+    let mut t = m.into_owned();
+    let mut u = Matrix::eye(t.shape()[0]);
+    let jacobi_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let (info, converged) =
+        crate::implementation::jacobi::jacobi_eigen(t.view_mut(), u.view_mut(), &jacobi_opts);
+    if !converged {
+        return Err(QRError::ConvergenceFailed);
+    }
+
+    let mut z = t.diag().into_owned();
+    sort_diagonal_values(z.view_mut(), u.view_mut());
+    Ok((z, u, info))
+}
+
+/// Computes the eigendecomposition of a symmetric matrix with the Jacobi algorithm
+///
+/// Outputs `(z, U, info)`.
+/// Uses the default symmetric options.
+/// See `jacobi_eigen_opts`.
+pub fn jacobi_eigen(m: MatrixView) -> Result<(Vector, Matrix, JacobiInfo)> {
+    jacobi_eigen_opts(m, &SYMMETRIC_OPTS)
+}
+
+/// Computes the SVD decomposition of a matrix with the one-sided Jacobi algorithm, accepts options
+///
+/// Outputs `(U, S, V^T, info)` in the same format as `svd_opts` and the convergence report `info`.
+///
+/// Applies cyclic sweeps of Jacobi rotations to the columns of `A` (of `A^T` if it has more columns than rows),
+/// skipping the pairs of columns with `|a_p^T a_q| <= opts.eps |a_p| |a_q|`, until a sweep performs no rotation.
+/// The column norms of the resulting `A V` are the singular values. `A^T A` is never formed, so for `A = B D` with diagonal `D`
+/// the singular values are computed to high relative accuracy whenever `B` is well conditioned.
+/// `opts.algorithm` is ignored and `opts.iterations` limits the number of sweeps.
+///
+/// Accepts any matrix with finite entries.
+/// Fails with `ConvergenceFailed` if the sweeps are exhausted.
+/// Performs O(n^3) operations per sweep.
+pub fn jacobi_svd_opts(
+    m: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, Vector, Matrix, JacobiInfo)> {
+    if opts.do_safety_checks && !finite_entries(m) {
+        return Err(QRError::NotFinite);
+    }
+
+    let (u, z, vt, info, converged) = crate::implementation::jacobi::jacobi_svd(m, opts);
+    if !converged {
+        return Err(QRError::ConvergenceFailed);
+    }
+
+    Ok((u, z, vt, info))
+}
+
+/// Computes the SVD decomposition of a matrix with the one-sided Jacobi algorithm
+///
+/// Outputs `(U, S, V^T, info)`.
+/// Uses the default symmetric options.
+/// See `jacobi_svd_opts`.
+pub fn jacobi_svd(m: MatrixView) -> Result<(Matrix, Vector, Matrix, JacobiInfo)> {
+    jacobi_svd_opts(m, &SYMMETRIC_OPTS)
+}
@@ -0,0 +1,261 @@
+use crate::implementation::checks::finite_entries;
+use crate::implementation::common::norm;
+use crate::implementation::lanczos::*;
+use crate::implementation::power::{shifted_lu, shifted_solve};
+use crate::*;
+
+use ndarray::{Array, Axis};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+#[inline]
+fn check_symmetric_operator(op: &impl LinearOperator, opts: &QROptions) -> Result<()> {
+    let (n, _) = op.shape();
+    let x: Vector = Array::random(n, Uniform::new(-1., 1.));
+    let ax = op.apply(x.view());
+    let atx = op.apply_transpose(x.view());
+    if ax.shape()[0] != n || atx.shape()[0] != n {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    if !ax.iter().chain(atx.iter()).all(|x| x.is_finite()) {
+        return Err(QRError::NotFinite);
+    }
+
+    if norm((&ax - &atx).view()) > opts.eps.sqrt() * norm(ax.view()) {
+        return Err(QRError::NotSymmetric);
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn check_lanczos_operator(op: &impl LinearOperator, k: usize, opts: &QROptions) -> Result<usize> {
+    if !op.is_square() {
+        return Err(QRError::NotSquare);
+    }
+
+    let (n, _) = op.shape();
+    if k == 0 || k > n {
+        return Err(QRError::InvalidParameter);
+    }
+
+    if opts.do_safety_checks {
+        check_symmetric_operator(op, opts)?;
+    }
+
+    Ok(n)
+}
+
+// Rayleigh quotients and the largest residual |A x - l x| of the columns x of u
+fn rayleigh_residuals(op: &impl LinearOperator, u: MatrixView) -> (Vector, f64) {
+    let mut residual: f64 = 0.;
+    let z = u
+        .gencolumns()
+        .into_iter()
+        .map(|x| {
+            let ax = op.apply(x);
+            let l = x.dot(&ax);
+            residual = residual.max(norm((ax - l * &x).view()));
+            l
+        })
+        .collect();
+    (z, residual)
+}
+
+/// Computes extreme eigenpairs of a large symmetric linear operator by the Lanczos algorithm, accepts options
+///
+/// Outputs `(z, U, info)` with the `k` wanted eigenvalues `z`, the orthonormal eigenvectors in the columns of the `n by k` matrix `U`
+/// and the convergence report `info` with the number of restarts and the largest residual `|A x - l x|`.
+/// The eigenvalues are in the descending order for `Largest` and in the ascending order for `Smallest`.
+///
+/// Builds an orthonormal basis of a Krylov space of size `max(2k, k + 20)` (or `n` if smaller) using only the products `A x`,
+/// approximates the eigenpairs by the Ritz pairs of the projected matrix, computed by the symmetric QR algorithm,
+/// and restarts from the best Ritz vectors (thick restart). `reorth` selects how the basis is kept orthogonal, see `Reorthogonalization`.
+/// Stops once the residual estimates of all wanted Ritz pairs are at most `opts.eps` times the estimated norm of the operator,
+/// after at most `opts.iterations` restarts; other options are ignored.
+/// For the eigenvalues closest to a shift see `lanczos_shift_invert_operator_opts`.
+///
+/// Accepts any nonempty square symmetric operator and `0 < k <= n`; when `opts.do_safety_checks` is set,
+/// symmetry is probed by comparing `A x` and `A^T x` for a random `x`.
+/// Fails with `ConvergenceFailed` if the wanted Ritz pairs do not converge in `opts.iterations` restarts,
+/// or if the computed residual exceeds `opts.eps` times the estimated norm of the operator.
+/// Performs O(k) products with `A` and O(nk^2) operations per restart.
+pub fn lanczos_opts(
+    op: &impl LinearOperator,
+    k: usize,
+    target: LanczosTarget,
+    reorth: Reorthogonalization,
+    opts: &QROptions,
+) -> Result<(Vector, Matrix, IterationInfo)> {
+    let n = check_lanczos_operator(op, k, opts)?;
+
+    let sign = match target {
+        LanczosTarget::Largest => 1.,
+        LanczosTarget::Smallest => -1.,
+    };
+    let (_, u, restarts, scale) =
+        thick_restart_lanczos(|x| sign * op.apply(x), n, k, false, reorth, opts)
+            .ok_or(QRError::ConvergenceFailed)?;
+
+    let (z, residual) = rayleigh_residuals(op, u.view());
+    if residual.is_nan() || residual > opts.eps * scale {
+        return Err(QRError::ConvergenceFailed);
+    }
+
+    Ok((
+        z,
+        u,
+        IterationInfo {
+            iterations: restarts,
+            residual,
+        },
+    ))
+}
+
+/// Computes extreme eigenpairs of a large symmetric linear operator by the Lanczos algorithm
+///
+/// Outputs `(z, U, info)`.
+/// Uses the default symmetric options and full reorthogonalization.
+/// See `lanczos_opts`.
+pub fn lanczos(
+    op: &impl LinearOperator,
+    k: usize,
+    target: LanczosTarget,
+) -> Result<(Vector, Matrix, IterationInfo)> {
+    lanczos_opts(op, k, target, Reorthogonalization::Full, &SYMMETRIC_OPTS)
+}
+
+/// Computes the eigenpairs of a large symmetric linear operator closest to a shift by the shift-invert Lanczos algorithm, accepts options
+///
+/// Outputs `(z, U, info)` with the `k` eigenvalues `z` closest to `sigma` in the ascending order of the distance to `sigma`,
+/// the orthonormal eigenvectors in the columns of the `n by k` matrix `U` and the convergence report `info`, see `lanczos_opts`.
+///
+/// Only needs the products `A x` of the square symmetric linear operator `A`, and `solve(b)` must return `(A - sigma I)^-1 b`,
+/// e.g. by a factorization computed once. Runs the Lanczos algorithm of `lanczos_opts` on `(A - sigma I)^-1`, whose eigenvalues
+/// `1 / (l - sigma)` largest in magnitude belong to the wanted eigenvalues `l`, and stops once the residual estimate of each
+/// wanted Ritz pair is at most `opts.eps` times its Ritz value. The eigenvalues are the Rayleigh quotients of the Ritz vectors.
+///
+/// Accepts any nonempty square symmetric operator, a closure returning vectors of the same length, finite `sigma` and `0 < k <= n`.
+/// Fails with `ConvergenceFailed` if the wanted Ritz pairs do not converge in `opts.iterations` restarts,
+/// or if the computed residual exceeds `opts.eps |A - sigma I|`, with `|A|` estimated by a few steps of power iteration.
+/// This happens in particular if `sigma` is an eigenvalue to working precision, since the other Ritz values are then
+/// below the rounding level of the largest one.
+/// Performs O(k) solves and O(nk^2) operations per restart.
+pub fn lanczos_shift_invert_operator_opts<G>(
+    op: &impl LinearOperator,
+    solve: G,
+    sigma: f64,
+    k: usize,
+    reorth: Reorthogonalization,
+    opts: &QROptions,
+) -> Result<(Vector, Matrix, IterationInfo)>
+where
+    G: Fn(VectorView) -> Vector,
+{
+    let n = check_lanczos_operator(op, k, opts)?;
+    if !sigma.is_finite() {
+        return Err(QRError::InvalidParameter);
+    }
+
+    let (_, u, restarts, _) =
+        thick_restart_lanczos(solve, n, k, true, reorth, opts).ok_or(QRError::ConvergenceFailed)?;
+
+    let (z, residual) = rayleigh_residuals(op, u.view());
+    let scale = norm_estimate(|x| op.apply(x), n) + sigma.abs();
+    if residual.is_nan() || residual > opts.eps * scale {
+        return Err(QRError::ConvergenceFailed);
+    }
+
+    let mut order: Vec<usize> = (0..k).collect();
+    order.sort_by(|i, j| {
+        (z[*i] - sigma)
+            .abs()
+            .partial_cmp(&(z[*j] - sigma).abs())
+            .unwrap()
+    });
+
+    Ok((
+        z.select(Axis(0), &order),
+        u.select(Axis(1), &order),
+        IterationInfo {
+            iterations: restarts,
+            residual,
+        },
+    ))
+}
+
+/// Computes the eigenpairs of a large symmetric linear operator closest to a shift by the shift-invert Lanczos algorithm
+///
+/// Outputs `(z, U, info)`.
+/// Uses the default symmetric options and full reorthogonalization.
+/// See `lanczos_shift_invert_operator_opts`.
+pub fn lanczos_shift_invert_operator<G>(
+    op: &impl LinearOperator,
+    solve: G,
+    sigma: f64,
+    k: usize,
+) -> Result<(Vector, Matrix, IterationInfo)>
+where
+    G: Fn(VectorView) -> Vector,
+{
+    lanczos_shift_invert_operator_opts(
+        op,
+        solve,
+        sigma,
+        k,
+        Reorthogonalization::Full,
+        &SYMMETRIC_OPTS,
+    )
+}
+
+/// Computes the eigenpairs of a symmetric matrix closest to a shift by the shift-invert Lanczos algorithm, accepts options
+///
+/// Outputs `(z, U, info)`.
+///
+/// Computes the LU decomposition of `A - sigma I` once and uses it for the solves. See `lanczos_shift_invert_operator_opts`.
+///
+/// Accepts any square symmetric matrix with finite entries, finite `sigma` and `0 < k <= n`.
+/// Performs O(n^3) operations for the decomposition and O(kn^2) operations per restart.
+pub fn lanczos_shift_invert_opts(
+    m: MatrixView,
+    sigma: f64,
+    k: usize,
+    reorth: Reorthogonalization,
+    opts: &QROptions,
+) -> Result<(Vector, Matrix, IterationInfo)> {
+    if opts.do_safety_checks && !finite_entries(m) {
+        return Err(QRError::NotFinite);
+    }
+
+    if !m.is_square() {
+        return Err(QRError::NotSquare);
+    }
+
+    if !sigma.is_finite() {
+        return Err(QRError::InvalidParameter);
+    }
+
+    let (lu, perm) = shifted_lu(m, sigma);
+    lanczos_shift_invert_operator_opts(
+        &m,
+        |b| shifted_solve(lu.view(), &perm, b),
+        sigma,
+        k,
+        reorth,
+        opts,
+    )
+}
+
+/// Computes the eigenpairs of a symmetric matrix closest to a shift by the shift-invert Lanczos algorithm
+///
+/// Outputs `(z, U, info)`.
+/// Uses the default symmetric options and full reorthogonalization.
+/// See `lanczos_shift_invert_opts`.
+pub fn lanczos_shift_invert(
+    m: MatrixView,
+    sigma: f64,
+    k: usize,
+) -> Result<(Vector, Matrix, IterationInfo)> {
+    lanczos_shift_invert_opts(m, sigma, k, Reorthogonalization::Full, &SYMMETRIC_OPTS)
+}
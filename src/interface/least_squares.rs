@@ -0,0 +1,72 @@
+use crate::implementation::checks::finite_entries;
+use crate::implementation::common::norm;
+use crate::implementation::least_squares::*;
+use crate::*;
+
+/// Solves a linear least-squares problem, accepts options
+///
+/// Outputs `(X, r, k)` where columns of `X` minimize `|AX - B|` column by column,
+/// `r` is the vector of residual norms `|Ax - b|` for each right-hand side and `k` is the effective rank of `A`.
+///
+/// If `A` has full column rank, the problem is solved via the Householder QR decomposition `A = QR` as `RX = Q^T B`.
+/// Otherwise (including the underdetermined case with fewer rows than columns) the SVD of `A` is used
+/// and the minimum-norm solution `X = V S^+ U^T B` is returned.
+/// Singular values are computed from the eigenvalues of `A A^T`, so the ones with `s^2 <= opts.eps * s_max^2` are treated as zero,
+/// and `A` is considered rank-deficient if a diagonal entry of `R` is below `opts.eps` relative to the largest one.
+///
+/// Accepts any matrices with finite entries and equal number of rows.
+/// Performs O(mn^2) operations on the QR path and O(m^3 + n^3) operations on the SVD path.
+pub fn least_squares_opts(
+    a: MatrixView,
+    b: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, Vector, usize)> {
+    if opts.do_safety_checks && (!finite_entries(a) || !finite_entries(b)) {
+        return Err(QRError::NotFinite);
+    }
+
+    if a.shape()[0] != b.shape()[0] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    let (x, rank) = match least_squares_qr(a, b, opts.eps) {
+        Some(x) => (x, a.shape()[1]),
+        None => least_squares_svd(a, b, opts.eps.sqrt(), opts),
+    };
+
+    let res = &a.dot(&x) - &b;
+    let r: Vector = res.gencolumns().into_iter().map(norm).collect();
+    Ok((x, r, rank))
+}
+
+/// Solves a linear least-squares problem
+///
+/// Outputs `(X, r, k)`.
+/// Uses default options.
+/// See `least_squares_opts`.
+pub fn least_squares(a: MatrixView, b: MatrixView) -> Result<(Matrix, Vector, usize)> {
+    least_squares_opts(a, b, &DEFAULT_OPTS)
+}
+
+/// Solves a linear least-squares problem with a single right-hand side, accepts options
+///
+/// Outputs `(x, r, k)` where `x` minimizes `|Ax - b|`, `r` is the residual norm and `k` is the effective rank of `A`.
+/// See `least_squares_opts`.
+pub fn least_squares_vector_opts(
+    a: MatrixView,
+    b: VectorView,
+    opts: &QROptions,
+) -> Result<(Vector, f64, usize)> {
+    let b = b.into_shape([b.shape()[0], 1usize]).unwrap();
+    let (x, r, rank) = least_squares_opts(a, b, opts)?;
+    Ok((x.column(0).into_owned(), r[0], rank))
+}
+
+/// Solves a linear least-squares problem with a single right-hand side
+///
+/// Outputs `(x, r, k)`.
+/// Uses default options.
+/// See `least_squares_opts`.
+pub fn least_squares_vector(a: MatrixView, b: VectorView) -> Result<(Vector, f64, usize)> {
+    least_squares_vector_opts(a, b, &DEFAULT_OPTS)
+}
@@ -0,0 +1,158 @@
+use crate::implementation::checks::finite_entries;
+use crate::implementation::lu::{lu_singular, lu_solve};
+use crate::*;
+
+#[inline]
+fn factorize(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Vec<usize>, f64)> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    let mut lu = m.into_owned();
+    let (perm, sign) = crate::implementation::lu::lu_decomposition(lu.view_mut());
+    Ok((lu, perm, sign))
+}
+
+#[inline]
+fn factorize_nonsingular(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Vec<usize>)> {
+    let (lu, perm, _) = factorize(m, opts)?;
+    if lu_singular(lu.view()) {
+        return Err(QRError::Singular);
+    }
+    Ok((lu, perm))
+}
+
+/// Computes the LU decomposition of a matrix with partial pivoting, accepts options
+///
+/// Outputs `(P, L, U)`.
+///
+/// Decomposition `PA = LU`, where `P` is a permutation matrix, `L` is lower triangular with unit diagonal and `U` is upper triangular,
+/// is called an LU decomposition with partial pivoting.
+/// The decomposition exists for singular matrices too, in which case `U` has zeros on its diagonal.
+///
+/// Accepts any square matrix with finite entries.
+/// Performs O(n^3) operations.
+pub fn lu_decomposition_opts(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Matrix, Matrix)> {
+    let (lu, perm, _) = factorize(m, opts)?;
+    let n = lu.shape()[0];
+
+    let mut p = Matrix::zeros((n, n));
+    let mut l = Matrix::eye(n);
+    let mut u = Matrix::zeros((n, n));
+    for i in 0..n {
+        p[[i, perm[i]]] = 1.;
+        for j in 0..n {
+            if j < i {
+                l[[i, j]] = lu[[i, j]];
+            } else {
+                u[[i, j]] = lu[[i, j]];
+            }
+        }
+    }
+
+    Ok((p, l, u))
+}
+
+/// Computes the LU decomposition of a matrix with partial pivoting
+///
+/// Outputs `(P, L, U)`.
+/// Uses default options.
+/// See `lu_decomposition_opts`.
+pub fn lu_decomposition(m: MatrixView) -> Result<(Matrix, Matrix, Matrix)> {
+    lu_decomposition_opts(m, &DEFAULT_OPTS)
+}
+
+/// Solves a linear system, accepts options
+///
+/// Outputs `X` such that `AX = B`. Columns of `B` are treated as separate right-hand sides.
+///
+/// Computes the LU decomposition of `A` with partial pivoting, then solves two triangular systems per right-hand side.
+///
+/// Accepts any non-singular square matrix with finite entries.
+/// Fails with `Singular` if `A` is numerically singular.
+/// Performs O(n^3) operations plus O(n^2) operations per right-hand side.
+pub fn solve_opts(a: MatrixView, b: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if opts.do_safety_checks && !finite_entries(b) {
+        return Err(QRError::NotFinite);
+    }
+
+    if a.shape()[0] != b.shape()[0] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    let (lu, perm) = factorize_nonsingular(a, opts)?;
+    Ok(lu_solve(lu.view(), &perm, b))
+}
+
+/// Solves a linear system
+///
+/// Outputs `X` such that `AX = B`.
+/// Uses default options.
+/// See `solve_opts`.
+pub fn solve(a: MatrixView, b: MatrixView) -> Result<Matrix> {
+    solve_opts(a, b, &DEFAULT_OPTS)
+}
+
+/// Solves a linear system with a single right-hand side, accepts options
+///
+/// Outputs `x` such that `Ax = b`.
+/// See `solve_opts`.
+pub fn solve_vector_opts(a: MatrixView, b: VectorView, opts: &QROptions) -> Result<Vector> {
+    let b = b.into_shape([b.shape()[0], 1usize]).unwrap();
+    let x = solve_opts(a, b, opts)?;
+    Ok(x.column(0).into_owned())
+}
+
+/// Solves a linear system with a single right-hand side
+///
+/// Outputs `x` such that `Ax = b`.
+/// Uses default options.
+/// See `solve_opts`.
+pub fn solve_vector(a: MatrixView, b: VectorView) -> Result<Vector> {
+    solve_vector_opts(a, b, &DEFAULT_OPTS)
+}
+
+/// Computes the determinant of a matrix, accepts options
+///
+/// Computes the LU decomposition with partial pivoting and multiplies the diagonal entries of `U`.
+///
+/// Accepts any square matrix with finite entries.
+/// Performs O(n^3) operations.
+pub fn determinant_opts(m: MatrixView, opts: &QROptions) -> Result<f64> {
+    let (lu, _, sign) = factorize(m, opts)?;
+    Ok(sign * lu.diag().iter().product::<f64>())
+}
+
+/// Computes the determinant of a matrix
+///
+/// Uses default options.
+/// See `determinant_opts`.
+pub fn determinant(m: MatrixView) -> Result<f64> {
+    determinant_opts(m, &DEFAULT_OPTS)
+}
+
+/// Computes the inverse of a matrix, accepts options
+///
+/// Solves `AX = I` using the LU decomposition with partial pivoting.
+///
+/// Accepts any non-singular square matrix with finite entries.
+/// Fails with `Singular` if `A` is numerically singular.
+/// Performs O(n^3) operations.
+pub fn inverse_opts(m: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    let (lu, perm) = factorize_nonsingular(m, opts)?;
+    Ok(lu_solve(lu.view(), &perm, Matrix::eye(m.shape()[0]).view()))
+}
+
+/// Computes the inverse of a matrix
+///
+/// Uses default options.
+/// See `inverse_opts`.
+pub fn inverse(m: MatrixView) -> Result<Matrix> {
+    inverse_opts(m, &DEFAULT_OPTS)
+}
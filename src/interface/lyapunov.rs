@@ -0,0 +1,161 @@
+use crate::implementation::checks::{diff_symm, finite_entries};
+use crate::implementation::cholesky::cholesky_pivoted;
+use crate::implementation::common::extract_eigenvalues;
+use crate::implementation::lyapunov::*;
+use crate::*;
+
+#[inline]
+fn lyapunov_schur_form(a: MatrixView, q: MatrixView, opts: &QROptions) -> Result<(Matrix, Matrix)> {
+    if opts.do_safety_checks {
+        if !finite_entries(a) || !finite_entries(q) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !a.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if q.shape() != a.shape() {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    let schur_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    schur_form_opts(a, &schur_opts)
+}
+
+/// Solves the continuous Lyapunov equation `A X + X A^T + Q = 0`, accepts options
+///
+/// The equation has a unique solution if and only if no two eigenvalues of `A` sum to zero; `QRError::Singular` is returned otherwise.
+/// If `A` is stable (all eigenvalues in the open left half-plane) and `Q` is positive semidefinite, so is `X`;
+/// for `Q = B B^T` it is the controllability Gramian of `(A, B)`.
+///
+/// Uses the Bartels-Stewart algorithm on the real Schur form `A = U T U^T` (see `sylvester_opts`);
+/// the Schur form of `A^T` is obtained from the same decomposition, so only one is computed.
+///
+/// Accepts square `A` and `Q` of equal size with finite entries.
+/// Performs O(n^3) operations.
+pub fn lyapunov_opts(a: MatrixView, q: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    let (t, u) = lyapunov_schur_form(a, q, opts)?;
+
+    let eigs = extract_eigenvalues(t.view());
+    let scale = eigs.iter().fold(0., |acc: f64, l| acc.max(l.norm()));
+    if eigs
+        .iter()
+        .any(|l| eigs.iter().any(|k| (l + k).norm() <= opts.eps * scale))
+    {
+        return Err(QRError::Singular);
+    }
+
+    Ok(lyapunov_continuous(t.view(), u.view(), q))
+}
+
+/// Solves the continuous Lyapunov equation `A X + X A^T + Q = 0`
+///
+/// Uses default options.
+/// See `lyapunov_opts`.
+pub fn lyapunov(a: MatrixView, q: MatrixView) -> Result<Matrix> {
+    lyapunov_opts(a, q, &DEFAULT_OPTS)
+}
+
+/// Solves the discrete Lyapunov (Stein) equation `A X A^T - X + Q = 0`, accepts options
+///
+/// The equation has a unique solution if and only if no two eigenvalues of `A` multiply to one; `QRError::Singular` is returned otherwise.
+/// If `A` is stable (all eigenvalues in the open unit disk) and `Q` is positive semidefinite, so is `X`;
+/// it is then the stationary covariance of `x <- A x + w` with noise covariance `Q`.
+///
+/// Uses the real Schur form `A = U T U^T` and solves the quasi-triangular equation block by block.
+///
+/// Accepts square `A` and `Q` of equal size with finite entries.
+/// Performs O(n^3) operations.
+pub fn discrete_lyapunov_opts(a: MatrixView, q: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    let (t, u) = lyapunov_schur_form(a, q, opts)?;
+
+    let eigs = extract_eigenvalues(t.view());
+    if eigs
+        .iter()
+        .any(|l| eigs.iter().any(|k| (l * k - 1.).norm() <= opts.eps))
+    {
+        return Err(QRError::Singular);
+    }
+
+    Ok(lyapunov_discrete(t.view(), u.view(), q))
+}
+
+/// Solves the discrete Lyapunov (Stein) equation `A X A^T - X + Q = 0`
+///
+/// Uses default options.
+/// See `discrete_lyapunov_opts`.
+pub fn discrete_lyapunov(a: MatrixView, q: MatrixView) -> Result<Matrix> {
+    discrete_lyapunov_opts(a, q, &DEFAULT_OPTS)
+}
+
+#[inline]
+fn semidefinite_factor(q: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        let diff = diff_symm(q);
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::NotSymmetric);
+        }
+    }
+
+    cholesky_pivoted(q, opts.eps).map_err(QRError::NotPositiveDefinite)
+}
+
+/// Computes the Cholesky factor of the solution of `A X + X A^T + Q = 0`, accepts options
+///
+/// Outputs a lower triangular `L` with non-negative diagonal such that `X = L L^T`.
+///
+/// `A` must be stable (all eigenvalues in the open left half-plane); `QRError::NotStable` is returned otherwise.
+/// `Q` must be symmetric positive semidefinite; `QRError::NotPositiveDefinite` is returned otherwise.
+/// `Q` may be singular, in which case so may be `L`.
+///
+/// Uses Hammarling's method: `Q = B B^T` is factored by the Cholesky decomposition with diagonal pivoting,
+/// which stops at the numerical rank of `Q` (pivots below `opts.eps` relative to the largest diagonal entry are dropped),
+/// and the factor of `X` is computed from `B` and the real Schur form of `A`, one diagonal block at a time,
+/// without forming `X`, which keeps it accurate for ill-conditioned Gramians.
+///
+/// Accepts square `A` and `Q` of equal size with finite entries.
+/// Performs O(n^3) operations.
+pub fn lyapunov_cholesky_opts(a: MatrixView, q: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    let (t, u) = lyapunov_schur_form(a, q, opts)?;
+    let b = semidefinite_factor(q, opts)?;
+    let r = hammarling(t.view(), u.t().dot(&b), false).ok_or(QRError::NotStable)?;
+    Ok(lower_factor(u.view(), r.view()))
+}
+
+/// Computes the Cholesky factor of the solution of `A X + X A^T + Q = 0`
+///
+/// Uses default options.
+/// See `lyapunov_cholesky_opts`.
+pub fn lyapunov_cholesky(a: MatrixView, q: MatrixView) -> Result<Matrix> {
+    lyapunov_cholesky_opts(a, q, &DEFAULT_OPTS)
+}
+
+/// Computes the Cholesky factor of the solution of `A X A^T - X + Q = 0`, accepts options
+///
+/// Outputs a lower triangular `L` with non-negative diagonal such that `X = L L^T`.
+///
+/// `A` must be stable (all eigenvalues in the open unit disk); `QRError::NotStable` is returned otherwise.
+/// See `lyapunov_cholesky_opts`.
+pub fn discrete_lyapunov_cholesky_opts(
+    a: MatrixView,
+    q: MatrixView,
+    opts: &QROptions,
+) -> Result<Matrix> {
+    let (t, u) = lyapunov_schur_form(a, q, opts)?;
+    let b = semidefinite_factor(q, opts)?;
+    let r = hammarling(t.view(), u.t().dot(&b), true).ok_or(QRError::NotStable)?;
+    Ok(lower_factor(u.view(), r.view()))
+}
+
+/// Computes the Cholesky factor of the solution of `A X A^T - X + Q = 0`
+///
+/// Uses default options.
+/// See `discrete_lyapunov_cholesky_opts`.
+pub fn discrete_lyapunov_cholesky(a: MatrixView, q: MatrixView) -> Result<Matrix> {
+    discrete_lyapunov_cholesky_opts(a, q, &DEFAULT_OPTS)
+}
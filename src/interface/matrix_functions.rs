@@ -0,0 +1,301 @@
+use crate::implementation::checks::{finite_entries, frob_norm};
+use crate::implementation::expm::*;
+use crate::implementation::logm::logm_quasi_triangular;
+use crate::implementation::lu::{lu_decomposition, lu_singular};
+use crate::implementation::schur_parlett::schur_parlett;
+use crate::implementation::signm::sign_newton;
+use crate::implementation::sqrtm::*;
+use crate::*;
+
+// Eigenvalues closer than this are evaluated together in one Taylor block
+const PARLETT_BLOCKING: f64 = 0.1;
+
+/// Computes the matrix exponential, accepts options
+///
+/// The matrix exponential is `exp(A) = I + A + A^2 / 2! + A^3 / 3! + ...`; `exp(At)` solves the linear ODE `x' = Ax`.
+///
+/// Uses scaling and squaring with a diagonal Pade approximant of degree 3, 5, 7, 9 or 13,
+/// chosen from the 1-norm of `A` so that the backward error stays below the unit roundoff.
+/// If `opts.algorithm == QRAlgorithm::Symmetric`, `A` must be symmetric and its eigendecomposition `A = U D U^T` is used instead: `exp(A) = U exp(D) U^T`
+/// (`U` is always accumulated, regardless of `opts.accumulate_sim_transforms`).
+///
+/// Accepts any square matrix with finite entries.
+/// Performs O(n^3 log |A|) operations.
+pub fn expm_opts(m: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if m.shape()[0] == 0 {
+        return Ok(m.into_owned());
+    }
+
+    if opts.algorithm == QRAlgorithm::Symmetric {
+        let sym_opts = QROptions {
+            accumulate_sim_transforms: true,
+            ..opts.clone()
+        };
+        let (t, u) = schur_form_opts(m, &sym_opts)?;
+        return Ok(symmetric_function(t.view(), u.view(), f64::exp));
+    }
+
+    Ok(expm_pade(m))
+}
+
+/// Computes the matrix exponential
+///
+/// Uses default options.
+/// See `expm_opts`.
+pub fn expm(m: MatrixView) -> Result<Matrix> {
+    expm_opts(m, &DEFAULT_OPTS)
+}
+
+/// Computes a general matrix function, accepts options
+///
+/// `f(z, k)` must return the `k`-th derivative of an analytic scalar function at `z`,
+/// and the function must be real on the real axis (`f(conj(z)) = conj(f(z))`), so that `f(A)` is real.
+///
+/// Uses the Schur-Parlett algorithm: the real Schur form `A = U T U^T` is reordered so that close eigenvalues form contiguous blocks,
+/// `f` is evaluated on the diagonal blocks by a Taylor series (or in closed form for single `2 by 2` blocks of complex eigenvalues,
+/// in real arithmetic), and the off-diagonal blocks are obtained from the Parlett recurrence by solving Sylvester equations.
+/// If `opts.algorithm == QRAlgorithm::Symmetric`, `A` must be symmetric and `f(A) = U f(D) U^T` is used instead.
+///
+/// Accepts any square matrix with finite entries, but the result is inaccurate if the blocks of close eigenvalues are highly non-normal.
+/// Performs O(n^3) operations for well-separated eigenvalues, O(n^4) at worst.
+pub fn funm_opts(
+    m: MatrixView,
+    f: impl Fn(Complex, usize) -> Complex,
+    opts: &QROptions,
+) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if m.shape()[0] == 0 {
+        return Ok(m.into_owned());
+    }
+
+    let schur_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let (mut t, mut u) = schur_form_opts(m, &schur_opts)?;
+
+    if opts.algorithm == QRAlgorithm::Symmetric {
+        return Ok(symmetric_function(t.view(), u.view(), |x| {
+            f(Complex::from(x), 0).re
+        }));
+    }
+
+    schur_parlett(t.view_mut(), u.view_mut(), &f, PARLETT_BLOCKING)
+        .ok_or(QRError::ConvergenceFailed)
+}
+
+/// Computes a general matrix function
+///
+/// Uses default options.
+/// See `funm_opts`.
+pub fn funm(m: MatrixView, f: impl Fn(Complex, usize) -> Complex) -> Result<Matrix> {
+    funm_opts(m, f, &DEFAULT_OPTS)
+}
+
+/// Computes the matrix sine
+///
+/// Uses default options.
+/// See `funm_opts`.
+pub fn sinm(m: MatrixView) -> Result<Matrix> {
+    funm(m, |z, k| match k % 4 {
+        0 => z.sin(),
+        1 => z.cos(),
+        2 => -z.sin(),
+        _ => -z.cos(),
+    })
+}
+
+/// Computes the matrix cosine
+///
+/// Uses default options.
+/// See `funm_opts`.
+pub fn cosm(m: MatrixView) -> Result<Matrix> {
+    funm(m, |z, k| match k % 4 {
+        0 => z.cos(),
+        1 => -z.sin(),
+        2 => -z.cos(),
+        _ => z.sin(),
+    })
+}
+
+/// Computes the matrix hyperbolic sine
+///
+/// Uses default options.
+/// See `funm_opts`.
+pub fn sinhm(m: MatrixView) -> Result<Matrix> {
+    funm(m, |z, k| if k % 2 == 0 { z.sinh() } else { z.cosh() })
+}
+
+/// Computes the matrix hyperbolic cosine
+///
+/// Uses default options.
+/// See `funm_opts`.
+pub fn coshm(m: MatrixView) -> Result<Matrix> {
+    funm(m, |z, k| if k % 2 == 0 { z.cosh() } else { z.sinh() })
+}
+
+#[inline]
+fn principal_schur_form(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Matrix, f64)> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    // A = c U T U^T with a power of two c close to |A|, so that the entries zeroed
+    // and the eigenvalues treated as zero are small relative to A
+    let norm = frob_norm(m);
+    let c = if norm > 0. {
+        2f64.powi(norm.log2().round() as i32)
+    } else {
+        1.
+    };
+
+    let schur_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let (t, u) = schur_form_opts((&m / c).view(), &schur_opts)?;
+
+    if !principal_branch_exists(t.view(), opts.eps) {
+        return Err(QRError::NoPrincipalBranch);
+    }
+
+    Ok((t, u, c))
+}
+
+/// Computes the principal matrix square root, accepts options
+///
+/// The principal square root of `A` is the unique `X` with `X^2 = A` whose eigenvalues have positive real parts.
+/// It exists if `A` has no eigenvalues on the closed negative real axis; `QRError::NoPrincipalBranch` is returned otherwise.
+///
+/// Uses the real Schur form `A = U T U^T` and the block Bjorck-Hammarling recurrence,
+/// which computes the square root of `T` block by block, one Sylvester equation per off-diagonal block.
+/// If `opts.algorithm == QRAlgorithm::Symmetric`, `A` must be symmetric and `sqrt(A) = U sqrt(D) U^T` is used instead.
+///
+/// Accepts any square matrix with finite entries; eigenvalues below `opts.eps` times the Frobenius norm of `A` are treated as zero.
+/// Performs O(n^3) operations.
+pub fn sqrtm_opts(m: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if m.shape()[0] == 0 {
+        return Ok(m.into_owned());
+    }
+
+    let (t, u, c) = principal_schur_form(m, opts)?;
+    if opts.algorithm == QRAlgorithm::Symmetric {
+        return Ok(c.sqrt() * symmetric_function(t.view(), u.view(), f64::sqrt));
+    }
+
+    let r = sqrtm_quasi_triangular(t.view());
+    Ok(c.sqrt() * u.dot(&r).dot(&u.t()))
+}
+
+/// Computes the principal matrix square root
+///
+/// Uses default options.
+/// See `sqrtm_opts`.
+pub fn sqrtm(m: MatrixView) -> Result<Matrix> {
+    sqrtm_opts(m, &DEFAULT_OPTS)
+}
+
+/// Computes the principal matrix logarithm, accepts options
+///
+/// The principal logarithm of `A` is the unique `X` with `exp(X) = A` whose eigenvalues have imaginary parts in `(-pi, pi)`.
+/// It exists if `A` has no eigenvalues on the closed negative real axis; `QRError::NoPrincipalBranch` is returned otherwise.
+///
+/// Uses inverse scaling and squaring on the real Schur form `A = U T U^T`:
+/// square roots of `T` are taken until `T^(1/2^s)` is close to the identity,
+/// then `log(A) = 2^s U r(T^(1/2^s) - I) U^T`, where `r` is a diagonal Pade approximant to `log(I + X)`.
+/// If `opts.algorithm == QRAlgorithm::Symmetric`, `A` must be symmetric and `log(A) = U log(D) U^T` is used instead.
+///
+/// Accepts any square matrix with finite entries; eigenvalues below `opts.eps` times the Frobenius norm of `A` are treated as zero.
+/// Performs O(n^3 log |A|) operations.
+pub fn logm_opts(m: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if m.shape()[0] == 0 {
+        return Ok(m.into_owned());
+    }
+
+    let (t, u, c) = principal_schur_form(m, opts)?;
+    let log_c = c.ln() * Matrix::eye(t.nrows());
+    if opts.algorithm == QRAlgorithm::Symmetric {
+        return Ok(symmetric_function(t.view(), u.view(), f64::ln) + log_c);
+    }
+
+    let r = logm_quasi_triangular(t.view()).ok_or(QRError::ConvergenceFailed)?;
+    Ok(u.dot(&r).dot(&u.t()) + log_c)
+}
+
+/// Computes the principal matrix logarithm
+///
+/// Uses default options.
+/// See `logm_opts`.
+pub fn logm(m: MatrixView) -> Result<Matrix> {
+    logm_opts(m, &DEFAULT_OPTS)
+}
+
+/// Computes the matrix sign function, accepts options
+///
+/// For `A = V J V^-1` in Jordan form, `sign(A) = V sign(J) V^-1`, where eigenvalues in the right half-plane are mapped to `1`
+/// and those in the left half-plane to `-1`. `(I + sign(A)) / 2` projects onto the invariant subspace of the right half-plane.
+/// The sign function is undefined if `A` has eigenvalues on the imaginary axis.
+///
+/// Uses the Newton iteration `X <- (mu X + (mu X)^-1) / 2` with determinant scaling `mu = |det X|^(-1/n)`,
+/// stopped once the relative change is below `opts.eps`; `opts.algorithm` is ignored.
+/// Returns `QRError::Singular` if `A` is singular and `QRError::ConvergenceFailed` if the iteration fails,
+/// which happens for eigenvalues on or close to the imaginary axis.
+///
+/// Accepts any square matrix with finite entries.
+/// Performs O(n^3) operations per iteration.
+pub fn signm_opts(m: MatrixView, opts: &QROptions) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if m.shape()[0] == 0 {
+        return Ok(m.into_owned());
+    }
+
+    let mut lu = m.into_owned();
+    let (perm, _) = lu_decomposition(lu.view_mut());
+    if lu_singular(lu.view()) {
+        return Err(QRError::Singular);
+    }
+
+    sign_newton(m, lu, perm, opts.eps, opts.iterations).ok_or(QRError::ConvergenceFailed)
+}
+
+/// Computes the matrix sign function
+///
+/// Uses default options.
+/// See `signm_opts`.
+pub fn signm(m: MatrixView) -> Result<Matrix> {
+    signm_opts(m, &DEFAULT_OPTS)
+}
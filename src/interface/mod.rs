@@ -1,15 +1,53 @@
+mod bisection;
+mod cholesky;
 mod eigenvalues;
 mod errors;
 mod hessenberg;
+mod invariant_subspace;
+mod jacobi;
+mod lanczos;
+mod least_squares;
+mod lu;
+mod lyapunov;
+mod matrix_functions;
+mod operator;
+mod pinv;
+mod polynomial;
+mod power;
 mod qr_decomposition;
+mod quadrature;
+mod qz;
+mod riccati;
 mod schur;
 mod svd;
+mod sylvester;
+mod symmetric_definite;
+mod tridiagonal;
 mod types;
 
+pub use bisection::*;
+pub use cholesky::*;
 pub use eigenvalues::*;
 pub use errors::*;
 pub use hessenberg::*;
+pub use invariant_subspace::*;
+pub use jacobi::*;
+pub use lanczos::*;
+pub use least_squares::*;
+pub use lu::*;
+pub use lyapunov::*;
+pub use matrix_functions::*;
+pub use operator::*;
+pub use pinv::*;
+pub use polynomial::*;
+pub use power::*;
 pub use qr_decomposition::*;
+pub use quadrature::*;
+pub use qz::*;
+pub use riccati::*;
 pub use schur::*;
 pub use svd::*;
+pub use sylvester::*;
+pub use symmetric_definite::*;
+pub use tridiagonal::*;
 pub use types::*;
@@ -0,0 +1,161 @@
+use crate::*;
+
+/// Linear operator `A` from `R^cols` to `R^rows` available through products with vectors
+///
+/// - `shape` --- `(rows, cols)` of the operator.
+/// - `apply` --- computes `A x` for `x` of length `cols`.
+/// - `apply_transpose` --- computes `A^T x` for `x` of length `rows`.
+///
+/// Implemented for dense matrices and their views, references to operators,
+/// and closures wrapped into `FnOperator` or `SymmetricFnOperator`.
+/// The methods may panic on vectors of wrong length; the algorithms check `shape` first.
+pub trait LinearOperator {
+    fn shape(&self) -> (usize, usize);
+    fn apply(&self, x: VectorView) -> Vector;
+    fn apply_transpose(&self, x: VectorView) -> Vector;
+
+    /// Checks whether the operator is square
+    fn is_square(&self) -> bool {
+        let (rows, cols) = self.shape();
+        rows == cols
+    }
+}
+
+impl LinearOperator for Matrix {
+    fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    fn apply(&self, x: VectorView) -> Vector {
+        self.dot(&x)
+    }
+
+    fn apply_transpose(&self, x: VectorView) -> Vector {
+        self.t().dot(&x)
+    }
+}
+
+impl LinearOperator for MatrixView<'_> {
+    fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    fn apply(&self, x: VectorView) -> Vector {
+        self.dot(&x)
+    }
+
+    fn apply_transpose(&self, x: VectorView) -> Vector {
+        self.t().dot(&x)
+    }
+}
+
+impl<T: LinearOperator + ?Sized> LinearOperator for &T {
+    fn shape(&self) -> (usize, usize) {
+        (**self).shape()
+    }
+
+    fn apply(&self, x: VectorView) -> Vector {
+        (**self).apply(x)
+    }
+
+    fn apply_transpose(&self, x: VectorView) -> Vector {
+        (**self).apply_transpose(x)
+    }
+}
+
+/// Linear operator given by closures computing `A x` and `A^T x`
+#[derive(Debug, Clone)]
+pub struct FnOperator<F, G> {
+    rows: usize,
+    cols: usize,
+    apply: F,
+    apply_transpose: G,
+}
+
+impl<F, G> FnOperator<F, G>
+where
+    F: Fn(VectorView) -> Vector,
+    G: Fn(VectorView) -> Vector,
+{
+    /// Creates a `rows by cols` operator from the products `apply(x) = A x` and `apply_transpose(x) = A^T x`
+    pub fn new(rows: usize, cols: usize, apply: F, apply_transpose: G) -> Self {
+        FnOperator {
+            rows,
+            cols,
+            apply,
+            apply_transpose,
+        }
+    }
+}
+
+impl<F, G> LinearOperator for FnOperator<F, G>
+where
+    F: Fn(VectorView) -> Vector,
+    G: Fn(VectorView) -> Vector,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: VectorView) -> Vector {
+        (self.apply)(x)
+    }
+
+    fn apply_transpose(&self, x: VectorView) -> Vector {
+        (self.apply_transpose)(x)
+    }
+}
+
+/// Symmetric linear operator given by a closure computing `A x = A^T x`
+#[derive(Debug, Clone)]
+pub struct SymmetricFnOperator<F> {
+    n: usize,
+    apply: F,
+}
+
+impl<F> SymmetricFnOperator<F>
+where
+    F: Fn(VectorView) -> Vector,
+{
+    /// Creates an `n by n` symmetric operator from the product `apply(x) = A x`
+    ///
+    /// Symmetry is not checked.
+    pub fn new(n: usize, apply: F) -> Self {
+        SymmetricFnOperator { n, apply }
+    }
+}
+
+impl<F> LinearOperator for SymmetricFnOperator<F>
+where
+    F: Fn(VectorView) -> Vector,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply(&self, x: VectorView) -> Vector {
+        (self.apply)(x)
+    }
+
+    fn apply_transpose(&self, x: VectorView) -> Vector {
+        (self.apply)(x)
+    }
+}
+
+/// Computes the dense matrix of a linear operator
+///
+/// Outputs the `rows by cols` matrix `A` with columns `A e_i`.
+///
+/// Mostly useful for testing and small operators.
+/// Performs `cols` applications of `A`.
+pub fn operator_matrix(op: &impl LinearOperator) -> Matrix {
+    let (rows, cols) = op.shape();
+    let mut m = Matrix::zeros((rows, cols));
+    let mut e = Vector::zeros(cols);
+    for (i, mut column) in m.gencolumns_mut().into_iter().enumerate() {
+        e[i] = 1.;
+        column.assign(&op.apply(e.view()));
+        e[i] = 0.;
+    }
+    m
+}
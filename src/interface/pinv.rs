@@ -0,0 +1,30 @@
+use crate::implementation::checks::finite_entries;
+use crate::implementation::least_squares::pseudoinverse;
+use crate::*;
+
+/// Computes the Moore-Penrose pseudoinverse of a matrix, accepts options
+///
+/// Outputs `(A^+, k)` where `k` is the number of singular values used, i.e. the effective rank of `A`.
+///
+/// For `A = U S V^T` the pseudoinverse is `A^+ = V S^+ U^T`, where `S^+` inverts the singular values above `rcond * s_max` and zeroes the rest.
+/// `A^+ b` is the minimum-norm least-squares solution of `Ax = b`.
+/// Singular values are computed from the eigenvalues of `A A^T`, so `rcond` below `sqrt(opts.eps)` does not resolve smaller singular values reliably.
+///
+/// Accepts any matrix with finite entries.
+/// Performs O(m^3 + n^3) operations.
+pub fn pinv_opts(m: MatrixView, rcond: f64, opts: &QROptions) -> Result<(Matrix, usize)> {
+    if opts.do_safety_checks && !finite_entries(m) {
+        return Err(QRError::NotFinite);
+    }
+
+    Ok(pseudoinverse(m, rcond, opts))
+}
+
+/// Computes the Moore-Penrose pseudoinverse of a matrix
+///
+/// Outputs `(A^+, k)`.
+/// Uses default options with `rcond = sqrt(eps)`, the cutoff used by `least_squares_opts`.
+/// See `pinv_opts`.
+pub fn pinv(m: MatrixView) -> Result<(Matrix, usize)> {
+    pinv_opts(m, DEFAULT_OPTS.eps.sqrt(), &DEFAULT_OPTS)
+}
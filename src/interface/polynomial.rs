@@ -0,0 +1,65 @@
+use crate::implementation::common::extract_eigenvalues;
+use crate::implementation::francis::qr_algorithm_francis;
+use crate::implementation::polynomial::*;
+use crate::*;
+
+use ndarray::s;
+
+/// Computes the roots of a real polynomial, accepts options
+///
+/// Coefficients are given from the highest degree down: `c[0] x^d + c[1] x^(d-1) + ... + c[d]`.
+/// Outputs the `d'` roots, counted with multiplicity, where `d'` is the degree after leading zero coefficients are dropped;
+/// complex roots come in conjugate pairs. Constant polynomials have no roots, while the zero polynomial yields `QRError::Singular`.
+///
+/// The roots are the eigenvalues of the companion matrix, which is already upper Hessenberg,
+/// so it is passed directly to the Francis algorithm without Hessenberg reduction; `opts.algorithm` is ignored.
+/// Trailing zero coefficients are split off as exact zero roots beforehand.
+/// If `polish == true`, every root is refined with a few Newton steps on the original polynomial, each accepted only if it decreases `|p|`.
+///
+/// Accepts any vector of finite coefficients.
+/// Performs O(d^3) operations.
+pub fn poly_roots_opts(c: VectorView, polish: bool, opts: &QROptions) -> Result<Vec<Complex>> {
+    if opts.do_safety_checks && !c.iter().all(|x| x.is_finite()) {
+        return Err(QRError::NotFinite);
+    }
+
+    let first = c.iter().position(|x| *x != 0.).ok_or(QRError::Singular)?;
+    let last = c.iter().rposition(|x| *x != 0.).unwrap();
+    let trimmed = c.slice(s![first..last + 1]);
+    let d = last - first;
+
+    let mut roots = Vec::with_capacity(c.shape()[0] - 1 - first);
+    if d > 0 {
+        let mut m = companion_matrix(trimmed);
+        let mut u = Matrix::eye(d);
+        let francis_opts = QROptions {
+            accumulate_sim_transforms: false,
+            ..opts.clone()
+        };
+        qr_algorithm_francis(m.view_mut(), u.view_mut(), &francis_opts);
+
+        if opts.do_safety_checks && !francis_converged(m.view()) {
+            return Err(QRError::ConvergenceFailed);
+        }
+
+        roots = extract_eigenvalues(m.view());
+        if polish {
+            roots = roots
+                .into_iter()
+                .map(|z| newton_polish(trimmed, z))
+                .collect();
+        }
+    }
+
+    roots.resize(c.shape()[0] - 1 - first, Complex::from(0.));
+    Ok(roots)
+}
+
+/// Computes the roots of a real polynomial
+///
+/// Coefficients are given from the highest degree down.
+/// Uses default eigenvalue options and Newton polishing.
+/// See `poly_roots_opts`.
+pub fn poly_roots(c: VectorView) -> Result<Vec<Complex>> {
+    poly_roots_opts(c, true, &EIGENVALUE_OPTS)
+}
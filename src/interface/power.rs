@@ -0,0 +1,292 @@
+use crate::implementation::checks::finite_entries;
+use crate::implementation::power::*;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+#[inline]
+fn check_square(m: MatrixView, opts: &QROptions) -> Result<()> {
+    if opts.do_safety_checks {
+        if !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !m.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn check_operator(op: &impl LinearOperator, x0: VectorView, opts: &QROptions) -> Result<()> {
+    if !op.is_square() {
+        return Err(QRError::NotSquare);
+    }
+
+    let (n, _) = op.shape();
+    if n == 0 || x0.shape()[0] != n {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    if opts.do_safety_checks {
+        if !x0.iter().all(|x| x.is_finite()) {
+            return Err(QRError::NotFinite);
+        }
+
+        if x0.iter().all(|x| *x == 0.) {
+            return Err(QRError::InvalidParameter);
+        }
+
+        if op.apply(x0).shape()[0] != n {
+            return Err(QRError::DimensionMismatch);
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn converged_pair(
+    result: (f64, Vector, IterationInfo, bool),
+) -> Result<(f64, Vector, IterationInfo)> {
+    let (lambda, x, info, converged) = result;
+    if !converged {
+        return Err(QRError::ConvergenceFailed);
+    }
+    Ok((lambda, x, info))
+}
+
+/// Computes the dominant eigenpair of a linear operator by power iteration, accepts options
+///
+/// Outputs `(l, x, info)` with the eigenvalue `l` of the largest absolute value, the corresponding eigenvector `x` with `|x| = 1`
+/// and the convergence report `info`.
+///
+/// Only needs the products `A x` of the square linear operator `A`.
+/// Starting from a random vector, repeats `x <- A x / |A x|` and estimates the eigenvalue by the Rayleigh quotient `l = x^T A x`.
+/// Stops once the residual `|A x - l x|` is at most `opts.eps` times the largest `|A x|` seen, after at most `opts.iterations` steps;
+/// other options are ignored. Converges linearly with the rate `|l_2 / l_1|`, where `l_2` is the second largest eigenvalue in absolute value.
+///
+/// Accepts any nonempty square operator.
+/// Fails with `ConvergenceFailed` if the dominant eigenvalue is not real and simple (e.g. a complex pair or `l` and `-l`),
+/// or if the residual does not become small enough in `opts.iterations` steps.
+/// Performs one application of `A` and O(n) operations per step.
+pub fn power_iteration_operator_opts(
+    op: &impl LinearOperator,
+    opts: &QROptions,
+) -> Result<(f64, Vector, IterationInfo)> {
+    let x0: Vector = Array::random(op.shape().0, Uniform::new(-1., 1.));
+    check_operator(op, x0.view(), opts)?;
+    converged_pair(vector_iteration(
+        |x| op.apply(x),
+        |_, y, _| Some(y.into_owned()),
+        x0.view(),
+        opts,
+    ))
+}
+
+/// Computes the dominant eigenpair of a linear operator by power iteration
+///
+/// Outputs `(l, x, info)`.
+/// Uses the default options.
+/// See `power_iteration_operator_opts`.
+pub fn power_iteration_operator(op: &impl LinearOperator) -> Result<(f64, Vector, IterationInfo)> {
+    power_iteration_operator_opts(op, &DEFAULT_OPTS)
+}
+
+/// Computes the dominant eigenpair of a matrix by power iteration, accepts options
+///
+/// Outputs `(l, x, info)`.
+///
+/// See `power_iteration_operator_opts`.
+///
+/// Accepts any square matrix with finite entries.
+/// Performs O(n^2) operations per step.
+pub fn power_iteration_opts(
+    m: MatrixView,
+    opts: &QROptions,
+) -> Result<(f64, Vector, IterationInfo)> {
+    check_square(m, opts)?;
+    power_iteration_operator_opts(&m, opts)
+}
+
+/// Computes the dominant eigenpair of a matrix by power iteration
+///
+/// Outputs `(l, x, info)`.
+/// Uses the default options.
+/// See `power_iteration_opts`.
+pub fn power_iteration(m: MatrixView) -> Result<(f64, Vector, IterationInfo)> {
+    power_iteration_opts(m, &DEFAULT_OPTS)
+}
+
+/// Computes the eigenpair of a linear operator closest to a shift by inverse iteration, accepts options
+///
+/// Outputs `(l, x, info)` with the eigenvalue `l` closest to `sigma`, the corresponding eigenvector `x` with `|x| = 1`
+/// and the convergence report `info`.
+///
+/// Only needs the products `A x` of the square linear operator `A`, and `solve(b)` must return `(A - sigma I)^-1 b`,
+/// e.g. by a factorization computed once. Runs power iteration on `(A - sigma I)^-1` from a random vector,
+/// with the same eigenvalue estimate and stopping criterion as `power_iteration_operator_opts`.
+/// Converges linearly with the rate `|l - sigma| / |l' - sigma|`, where `l'` is the second closest eigenvalue to `sigma`.
+///
+/// Accepts any nonempty square operator and a closure returning vectors of the same length.
+/// Fails with `ConvergenceFailed` if the eigenvalue closest to `sigma` is not real and simple, or if the residual does not become small enough in `opts.iterations` steps.
+/// Performs one application of `A`, one solve and O(n) operations per step.
+pub fn shifted_inverse_iteration_operator_opts<G>(
+    op: &impl LinearOperator,
+    solve: G,
+    opts: &QROptions,
+) -> Result<(f64, Vector, IterationInfo)>
+where
+    G: Fn(VectorView) -> Vector,
+{
+    let x0: Vector = Array::random(op.shape().0, Uniform::new(-1., 1.));
+    check_operator(op, x0.view(), opts)?;
+    converged_pair(vector_iteration(
+        |x| op.apply(x),
+        |x, _, _| Some(solve(x)),
+        x0.view(),
+        opts,
+    ))
+}
+
+/// Computes the eigenpair of a linear operator closest to a shift by inverse iteration
+///
+/// Outputs `(l, x, info)`.
+/// Uses the default options.
+/// See `shifted_inverse_iteration_operator_opts`.
+pub fn shifted_inverse_iteration_operator<G>(
+    op: &impl LinearOperator,
+    solve: G,
+) -> Result<(f64, Vector, IterationInfo)>
+where
+    G: Fn(VectorView) -> Vector,
+{
+    shifted_inverse_iteration_operator_opts(op, solve, &DEFAULT_OPTS)
+}
+
+/// Computes the eigenpair of a matrix closest to a shift by inverse iteration, accepts options
+///
+/// Outputs `(l, x, info)`.
+///
+/// Computes the LU decomposition of `A - sigma I` once, replacing tiny pivots so that `sigma` may be an eigenvalue,
+/// and uses it for the solves. See `shifted_inverse_iteration_operator_opts`.
+///
+/// Accepts any square matrix with finite entries and finite `sigma`.
+/// Performs O(n^3) operations for the decomposition and O(n^2) operations per step.
+pub fn shifted_inverse_iteration_opts(
+    m: MatrixView,
+    sigma: f64,
+    opts: &QROptions,
+) -> Result<(f64, Vector, IterationInfo)> {
+    check_square(m, opts)?;
+    if !sigma.is_finite() {
+        return Err(QRError::InvalidParameter);
+    }
+
+    let (lu, perm) = shifted_lu(m, sigma);
+    shifted_inverse_iteration_operator_opts(&m, |b| shifted_solve(lu.view(), &perm, b), opts)
+}
+
+/// Computes the eigenpair of a matrix closest to a shift by inverse iteration
+///
+/// Outputs `(l, x, info)`.
+/// Uses the default options.
+/// See `shifted_inverse_iteration_opts`.
+pub fn shifted_inverse_iteration(
+    m: MatrixView,
+    sigma: f64,
+) -> Result<(f64, Vector, IterationInfo)> {
+    shifted_inverse_iteration_opts(m, sigma, &DEFAULT_OPTS)
+}
+
+/// Computes an eigenpair of a linear operator by Rayleigh quotient iteration, accepts options
+///
+/// Outputs `(l, x, info)` with an eigenvalue `l`, the corresponding eigenvector `x` with `|x| = 1` and the convergence report `info`.
+///
+/// Only needs the products `A x` of the square linear operator `A`, and `solve(s, b)` must return `(A - s I)^-1 b`
+/// for any shift `s`, or `None` if it cannot. Starting from `x0`, repeats inverse iteration steps `x <- (A - l I)^-1 x`
+/// with the shift updated to the current Rayleigh quotient `l = x^T A x`, with the same stopping criterion as `power_iteration_operator_opts`.
+/// Usually converges to the eigenpair whose eigenvector is closest to `x0`, cubically for symmetric `A` and quadratically otherwise.
+///
+/// Accepts any nonempty square operator, a closure returning vectors of the same length and a finite nonzero `x0` of matching length.
+/// Fails with `ConvergenceFailed` if `solve` fails before the residual becomes small enough, or if it does not in `opts.iterations` steps.
+/// Performs one application of `A`, one solve with a new shift and O(n) operations per step.
+pub fn rayleigh_quotient_iteration_operator_opts<G>(
+    op: &impl LinearOperator,
+    solve: G,
+    x0: VectorView,
+    opts: &QROptions,
+) -> Result<(f64, Vector, IterationInfo)>
+where
+    G: Fn(f64, VectorView) -> Option<Vector>,
+{
+    check_operator(op, x0, opts)?;
+    converged_pair(vector_iteration(
+        |x| op.apply(x),
+        |x, _, lambda| solve(lambda, x),
+        x0,
+        opts,
+    ))
+}
+
+/// Computes an eigenpair of a linear operator by Rayleigh quotient iteration
+///
+/// Outputs `(l, x, info)`.
+/// Uses the default options.
+/// See `rayleigh_quotient_iteration_operator_opts`.
+pub fn rayleigh_quotient_iteration_operator<G>(
+    op: &impl LinearOperator,
+    solve: G,
+    x0: VectorView,
+) -> Result<(f64, Vector, IterationInfo)>
+where
+    G: Fn(f64, VectorView) -> Option<Vector>,
+{
+    rayleigh_quotient_iteration_operator_opts(op, solve, x0, &DEFAULT_OPTS)
+}
+
+/// Computes an eigenpair of a matrix by Rayleigh quotient iteration, accepts options
+///
+/// Outputs `(l, x, info)`.
+///
+/// Computes a new LU decomposition of `A - l I` in every step, replacing tiny pivots so that `l` may be an eigenvalue.
+/// See `rayleigh_quotient_iteration_operator_opts`.
+///
+/// Accepts any square matrix with finite entries and a finite nonzero `x0` of matching length.
+/// Performs O(n^3) operations per step.
+pub fn rayleigh_quotient_iteration_opts(
+    m: MatrixView,
+    x0: VectorView,
+    opts: &QROptions,
+) -> Result<(f64, Vector, IterationInfo)> {
+    check_square(m, opts)?;
+    if x0.shape()[0] != m.shape()[0] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    rayleigh_quotient_iteration_operator_opts(
+        &m,
+        |s, b| {
+            let (lu, perm) = shifted_lu(m, s);
+            Some(shifted_solve(lu.view(), &perm, b))
+        },
+        x0,
+        opts,
+    )
+}
+
+/// Computes an eigenpair of a matrix by Rayleigh quotient iteration
+///
+/// Outputs `(l, x, info)`.
+/// Uses the default options.
+/// See `rayleigh_quotient_iteration_opts`.
+pub fn rayleigh_quotient_iteration(
+    m: MatrixView,
+    x0: VectorView,
+) -> Result<(f64, Vector, IterationInfo)> {
+    rayleigh_quotient_iteration_opts(m, x0, &DEFAULT_OPTS)
+}
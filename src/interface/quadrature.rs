@@ -0,0 +1,49 @@
+use crate::implementation::quadrature::golub_welsch;
+use crate::*;
+
+/// Computes the nodes and weights of a Gauss quadrature rule, accepts options
+///
+/// Outputs `(x, w)` with nodes `x` in ascending order, such that `sum w_i f(x_i)` approximates the integral of `f` against the weight function of the rule.
+/// The approximation is exact for polynomials of degree up to `2n - 1`.
+///
+/// Uses the Golub-Welsch algorithm: the nodes are the eigenvalues of the symmetric tridiagonal Jacobi matrix
+/// built from the three-term recurrence of the orthogonal polynomials, and the weights are `mu_0 v_1^2`,
+/// where `v_1` are the first components of the normalized eigenvectors and `mu_0` is the integral of the weight function.
+/// Only the first row of the eigenvector matrix is accumulated; `opts.algorithm` is ignored.
+/// Returns `QRError::InvalidParameter` if the parameters of the rule are out of range
+/// and `QRError::ConvergenceFailed` if the QR iteration does not converge within `opts.iterations`.
+///
+/// Performs O(n^2) operations.
+pub fn gauss_quadrature_opts(
+    rule: QuadratureRule,
+    n: usize,
+    opts: &QROptions,
+) -> Result<(Vector, Vector)> {
+    let valid = match rule {
+        QuadratureRule::Legendre | QuadratureRule::Hermite => true,
+        QuadratureRule::Laguerre(al) => al > -1.,
+        QuadratureRule::Jacobi(al, be) => al > -1. && be > -1.,
+    };
+    if !valid {
+        return Err(QRError::InvalidParameter);
+    }
+
+    if n == 0 {
+        return Ok((Vector::zeros(0), Vector::zeros(0)));
+    }
+
+    golub_welsch(rule, n, opts).ok_or(QRError::ConvergenceFailed)
+}
+
+/// Computes the nodes and weights of a Gauss quadrature rule
+///
+/// Outputs `(x, w)`.
+/// Uses symmetric options with `eps` set to the machine epsilon, so that the nodes and weights are accurate to roundoff.
+/// See `gauss_quadrature_opts`.
+pub fn gauss_quadrature(rule: QuadratureRule, n: usize) -> Result<(Vector, Vector)> {
+    let opts = QROptions {
+        eps: f64::EPSILON,
+        ..SYMMETRIC_OPTS
+    };
+    gauss_quadrature_opts(rule, n, &opts)
+}
@@ -0,0 +1,182 @@
+use crate::implementation::checks::{diff_subtriag, diff_triag, finite_entries};
+use crate::implementation::common::zero_subeps_entries;
+use crate::implementation::qz::{
+    extract_generalized_eigenvalues, qz_algorithm, qz_block_reduction,
+};
+use crate::*;
+
+#[inline]
+fn check_pencil(a: MatrixView, b: MatrixView, opts: &QROptions) -> Result<()> {
+    if opts.do_safety_checks {
+        if !finite_entries(a) || !finite_entries(b) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !a.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if a.shape() != b.shape() {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    Ok(())
+}
+
+/// Computes the Hessenberg-triangular form of a matrix pencil, accepts options
+///
+/// Outputs `(H, R, Q, Z)`, with `Q` and `Z` accumulated if desired (`opts.accumulate_sim_transforms == true`).
+///
+/// The Hessenberg-triangular form of a pencil `(A, B)` is a pair `(H, R)` where `H` is a Hessenberg matrix,
+/// `R` is upper triangular, `A = Q H Z^T` and `B = Q R Z^T` for orthogonal `Q` and `Z`.
+///
+/// Accepts any pair of square matrices of equal size with finite entries.
+/// Performs O(n^3) operations.
+pub fn hessenberg_triangular_form_opts(
+    a: MatrixView,
+    b: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, Matrix, Matrix, Matrix)> {
+    check_pencil(a, b, opts)?;
+
+    let n = a.shape()[0];
+    let (mut h, mut r) = (a.into_owned(), b.into_owned());
+    let (mut q, mut z) = (Matrix::eye(n), Matrix::eye(n));
+    crate::implementation::qz::hessenberg_triangular_form(
+        h.view_mut(),
+        r.view_mut(),
+        q.view_mut(),
+        z.view_mut(),
+        opts,
+    );
+    if opts.zero_entries {
+        zero_subeps_entries(h.view_mut(), opts.eps);
+        zero_subeps_entries(r.view_mut(), opts.eps);
+    }
+
+    Ok((h, r, q, z))
+}
+
+/// Computes the Hessenberg-triangular form of a matrix pencil
+///
+/// Outputs `(H, R, Q, Z)`.
+/// Uses default options.
+/// See `hessenberg_triangular_form_opts`.
+pub fn hessenberg_triangular_form(
+    a: MatrixView,
+    b: MatrixView,
+) -> Result<(Matrix, Matrix, Matrix, Matrix)> {
+    hessenberg_triangular_form_opts(a, b, &DEFAULT_OPTS)
+}
+
+/// Computes the generalized (real) Schur form of a matrix pencil in-place, accepts options
+///
+/// Outputs `(Q, Z)`, accumulated if desired (`opts.accumulate_sim_transforms == true`).
+///
+/// Generalized real Schur form of a pencil `(A, B)` is a decomposition `A = Q S Z^T`, `B = Q T Z^T`,
+/// where `Q` and `Z` are orthogonal, `S` is quasi-triangular and `T` is upper triangular.
+/// `S` may have `2 by 2` blocks on its diagonal representing pairs of complex conjugate generalized eigenvalues.
+/// Generalized eigenvalues are the numbers `l` for which `A - l B` is singular;
+/// `B` may be singular, in which case some of them are infinite (zero on the diagonal of `T`).
+///
+/// Uses Hessenberg-triangular reduction followed by the double-shift QZ algorithm; `opts.algorithm` is ignored.
+/// Accepts any pair of square matrices of equal size with finite entries, but convergence is not guaranteed.
+pub fn generalized_schur_form_inplace_opts(
+    mut a: MatrixViewMut,
+    mut b: MatrixViewMut,
+    opts: &QROptions,
+) -> Result<(Matrix, Matrix)> {
+    check_pencil(a.view(), b.view(), opts)?;
+
+    let n = a.shape()[0];
+    let (mut q, mut z) = (Matrix::eye(n), Matrix::eye(n));
+    crate::implementation::qz::hessenberg_triangular_form(
+        a.view_mut(),
+        b.view_mut(),
+        q.view_mut(),
+        z.view_mut(),
+        opts,
+    );
+    qz_algorithm(a.view_mut(), b.view_mut(), q.view_mut(), z.view_mut(), opts);
+    qz_block_reduction(a.view_mut(), b.view_mut(), q.view_mut(), z.view_mut(), opts);
+
+    if opts.zero_entries {
+        zero_subeps_entries(a.view_mut(), opts.eps);
+        zero_subeps_entries(b.view_mut(), opts.eps);
+    }
+
+    if opts.do_safety_checks && n > 0 {
+        let diff = diff_subtriag(a.view()) + diff_triag(b.view());
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::ConvergenceFailed);
+        }
+    }
+
+    Ok((q, z))
+}
+
+/// Computes the generalized (real) Schur form of a matrix pencil in-place
+///
+/// Outputs `(Q, Z)`.
+/// Uses default options.
+/// See `generalized_schur_form_inplace_opts`.
+pub fn generalized_schur_form_inplace(
+    a: MatrixViewMut,
+    b: MatrixViewMut,
+) -> Result<(Matrix, Matrix)> {
+    generalized_schur_form_inplace_opts(a, b, &DEFAULT_OPTS)
+}
+
+/// Computes the generalized (real) Schur form of a matrix pencil, accepts options
+///
+/// Outputs `(S, T, Q, Z)`.
+/// See `generalized_schur_form_inplace_opts`.
+pub fn generalized_schur_form_opts(
+    a: MatrixView,
+    b: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, Matrix, Matrix, Matrix)> {
+    let (mut s, mut t) = (a.into_owned(), b.into_owned());
+    let (q, z) = generalized_schur_form_inplace_opts(s.view_mut(), t.view_mut(), opts)?;
+    Ok((s, t, q, z))
+}
+
+/// Computes the generalized (real) Schur form of a matrix pencil
+///
+/// Outputs `(S, T, Q, Z)`.
+/// Uses default options.
+/// See `generalized_schur_form_inplace_opts`.
+pub fn generalized_schur_form(
+    a: MatrixView,
+    b: MatrixView,
+) -> Result<(Matrix, Matrix, Matrix, Matrix)> {
+    generalized_schur_form_opts(a, b, &DEFAULT_OPTS)
+}
+
+/// Computes the generalized eigenvalues of a matrix pencil, accepts options
+///
+/// Outputs a vector of pairs `(alpha, beta)` with `beta >= 0`; the eigenvalue is `alpha / beta`.
+/// Pairs with `beta == 0` represent infinite eigenvalues.
+///
+/// Complex number `l` is a generalized eigenvalue of `(A, B)` if there exists a non-zero complex vector `v` for which `Av = lBv`.
+///
+/// Calculates the generalized Schur form of the pencil and extracts the eigenvalues from the diagonals of `S` and `T`.
+/// See `generalized_schur_form_inplace_opts`.
+pub fn generalized_eigenvalues_opts(
+    a: MatrixView,
+    b: MatrixView,
+    opts: &QROptions,
+) -> Result<Vec<(Complex, f64)>> {
+    let (s, t, _, _) = generalized_schur_form_opts(a, b, opts)?;
+    Ok(extract_generalized_eigenvalues(s.view(), t.view()))
+}
+
+/// Computes the generalized eigenvalues of a matrix pencil
+///
+/// Outputs a vector of pairs `(alpha, beta)`.
+/// Uses default eigenvalue options.
+/// See `generalized_eigenvalues_opts`.
+pub fn generalized_eigenvalues(a: MatrixView, b: MatrixView) -> Result<Vec<(Complex, f64)>> {
+    generalized_eigenvalues_opts(a, b, &EIGENVALUE_OPTS)
+}
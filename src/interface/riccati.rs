@@ -0,0 +1,183 @@
+use crate::implementation::checks::{diff_symm, finite_entries};
+use crate::implementation::cholesky::cholesky;
+use crate::implementation::common::{extract_eigenvalues, schur_blocks};
+use crate::implementation::qz::extract_generalized_eigenvalues;
+use crate::implementation::reorder::{reorder_generalized_schur, reorder_schur};
+use crate::implementation::riccati::*;
+use crate::implementation::triangular::*;
+use crate::*;
+
+use ndarray::s;
+
+#[inline]
+fn check_riccati(
+    a: MatrixView,
+    b: MatrixView,
+    q: MatrixView,
+    r: MatrixView,
+    opts: &QROptions,
+) -> Result<Matrix> {
+    if opts.do_safety_checks {
+        if !finite_entries(a) || !finite_entries(b) || !finite_entries(q) || !finite_entries(r) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !a.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    let (n, m) = (b.shape()[0], b.shape()[1]);
+    if a.shape()[0] != n || q.shape() != [n, n] || r.shape() != [m, m] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    if opts.do_safety_checks {
+        let diff = diff_symm(q) + diff_symm(r);
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::NotSymmetric);
+        }
+    }
+
+    // G = B R^-1 B^T = (L^-1 B^T)^T (L^-1 B^T)
+    let mut l = r.into_owned();
+    cholesky(l.view_mut()).map_err(QRError::NotPositiveDefinite)?;
+    let mut lb = b.t().into_owned();
+    solve_lower_triangular(l.view(), lb.view_mut());
+    Ok(lb.t().dot(&lb))
+}
+
+#[inline]
+fn stabilizing_solution(h: Matrix, opts: &QROptions) -> Result<(Matrix, Vec<Complex>)> {
+    let n = h.shape()[0] / 2;
+    let schur_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let (mut t, mut u) = schur_form_opts(h.view(), &schur_opts)?;
+
+    let mut select = vec![false; 2 * n];
+    for (i, sz) in schur_blocks(t.view()) {
+        let l = extract_eigenvalues(t.slice(s![i..i + sz, i..i + sz]))[0];
+        select[i..i + sz].iter_mut().for_each(|x| *x = l.re < 0.);
+    }
+    if select.iter().filter(|x| **x).count() != n {
+        return Err(QRError::NoStabilizingSolution);
+    }
+
+    reorder_schur(t.view_mut(), u.view_mut(), &select).ok_or(QRError::ReorderingFailed)?;
+    let x = riccati_solution(u.view(), opts.eps).ok_or(QRError::NoStabilizingSolution)?;
+    Ok((x, extract_eigenvalues(t.slice(s![0..n, 0..n]))))
+}
+
+/// Solves the continuous algebraic Riccati equation, accepts options
+///
+/// Outputs `(X, l)` where `X` is the stabilizing solution and `l` are the closed-loop eigenvalues, the eigenvalues of `A - B K` with `K = R^-1 B^T X`.
+///
+/// The continuous algebraic Riccati equation is `A^T X + X A - X B R^-1 B^T X + Q = 0`.
+/// Its stabilizing solution is the symmetric `X` for which `A - B K` is stable (all eigenvalues in the open left half-plane);
+/// `K` is then the optimal LQR gain. It exists if `(A, B)` is stabilizable and the Hamiltonian matrix has no eigenvalues on the imaginary axis,
+/// which holds, for instance, if `Q` is positive semidefinite and `(Q, A)` is detectable. `QRError::NoStabilizingSolution` is returned otherwise.
+///
+/// Uses the real Schur form of the Hamiltonian matrix `H = [[A, -B R^-1 B^T], [-Q, -A^T]]`, reordered so that its stable eigenvalues come first
+/// (see `reorder_schur_inplace_opts`); with the leading `n` Schur vectors `[U1; U2]`, `X = U2 U1^-1`.
+///
+/// Accepts square `A` (`n by n`), `B` (`n by m`), symmetric `Q` (`n by n`) and symmetric positive definite `R` (`m by m`) with finite entries.
+/// Performs O(n^3 + m^3) operations.
+pub fn care_opts(
+    a: MatrixView,
+    b: MatrixView,
+    q: MatrixView,
+    r: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, Vec<Complex>)> {
+    let g = check_riccati(a, b, q, r, opts)?;
+    stabilizing_solution(hamiltonian(a, g.view(), q), opts)
+}
+
+/// Solves the continuous algebraic Riccati equation
+///
+/// Outputs `(X, l)`.
+/// Uses default options.
+/// See `care_opts`.
+pub fn care(
+    a: MatrixView,
+    b: MatrixView,
+    q: MatrixView,
+    r: MatrixView,
+) -> Result<(Matrix, Vec<Complex>)> {
+    care_opts(a, b, q, r, &DEFAULT_OPTS)
+}
+
+/// Solves the discrete algebraic Riccati equation, accepts options
+///
+/// Outputs `(X, l)` where `X` is the stabilizing solution and `l` are the closed-loop eigenvalues, the eigenvalues of `A - B K` with `K = (R + B^T X B)^-1 B^T X A`.
+///
+/// The discrete algebraic Riccati equation is `A^T X A - X - A^T X B (R + B^T X B)^-1 B^T X A + Q = 0`.
+/// Its stabilizing solution is the symmetric `X` for which `A - B K` is stable (all eigenvalues in the open unit disk);
+/// `K` is then the optimal discrete LQR gain, and the same equation for `(A^T, C^T)` yields the steady-state Kalman filter.
+/// `QRError::NoStabilizingSolution` is returned if it does not exist, see `care_opts`.
+///
+/// Uses the generalized Schur form (see `generalized_schur_form_opts`) of the extended symplectic pencil
+/// `[[A, 0, B], [-Q, I, 0], [0, 0, R]] - l [[I, 0, 0], [0, A^T, 0], [0, -B^T, 0]]`, compressed to size `2n` by an orthogonal transformation
+/// and reordered so that the eigenvalues inside the unit disk come first; with the leading `n` right Schur vectors `[Z1; Z2]`, `X = Z2 Z1^-1`.
+/// Neither `A` nor `R` is inverted, so singular `A` is allowed.
+///
+/// Accepts square `A` (`n by n`), `B` (`n by m`), symmetric `Q` (`n by n`) and symmetric positive definite `R` (`m by m`) with finite entries.
+/// Performs O(n^3 + m^3) operations.
+pub fn dare_opts(
+    a: MatrixView,
+    b: MatrixView,
+    q: MatrixView,
+    r: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, Vec<Complex>)> {
+    check_riccati(a, b, q, r, opts)?;
+
+    let n = a.shape()[0];
+    let (h, j) = symplectic_pencil(a, b, q, r);
+    let schur_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let (mut s, mut t, mut qm, mut z) =
+        generalized_schur_form_opts(h.view(), j.view(), &schur_opts)?;
+
+    let mut select = vec![false; 2 * n];
+    for (i, sz) in schur_blocks(s.view()) {
+        let block = s![i..i + sz, i..i + sz];
+        let (alpha, beta) = extract_generalized_eigenvalues(s.slice(block), t.slice(block))[0];
+        select[i..i + sz]
+            .iter_mut()
+            .for_each(|x| *x = alpha.norm() < beta);
+    }
+    if select.iter().filter(|x| **x).count() != n {
+        return Err(QRError::NoStabilizingSolution);
+    }
+
+    reorder_generalized_schur(
+        s.view_mut(),
+        t.view_mut(),
+        qm.view_mut(),
+        z.view_mut(),
+        &select,
+    )
+    .ok_or(QRError::ReorderingFailed)?;
+    let x = riccati_solution(z.view(), opts.eps).ok_or(QRError::NoStabilizingSolution)?;
+    let eigs = extract_generalized_eigenvalues(s.slice(s![0..n, 0..n]), t.slice(s![0..n, 0..n]));
+    Ok((x, eigs.iter().map(|(alpha, beta)| alpha / beta).collect()))
+}
+
+/// Solves the discrete algebraic Riccati equation
+///
+/// Outputs `(X, l)`.
+/// Uses default options.
+/// See `dare_opts`.
+pub fn dare(
+    a: MatrixView,
+    b: MatrixView,
+    q: MatrixView,
+    r: MatrixView,
+) -> Result<(Matrix, Vec<Complex>)> {
+    dare_opts(a, b, q, r, &DEFAULT_OPTS)
+}
@@ -1,7 +1,10 @@
 use crate::implementation::checks::{diff_subtriag, diff_symm, diff_triag, finite_entries};
 use crate::implementation::common::zero_subeps_entries;
+use crate::implementation::divide_conquer::divide_and_conquer_symmetric;
 use crate::implementation::francis::{francis_block_reduction, qr_algorithm_francis};
 use crate::implementation::hessenberg::{hessenberg_form, qr_algorithm_hessenberg};
+use crate::implementation::jacobi::jacobi_eigen;
+use crate::implementation::mrrr::mrrr_symmetric;
 use crate::implementation::qr_basic::qr_algorithm_naive;
 use crate::implementation::qr_symmetric::qr_algorithm_symmetric;
 use crate::*;
@@ -26,8 +29,18 @@ pub fn schur_form_inplace_opts(mut m: MatrixViewMut, opts: &QROptions) -> Result
             return Err(QRError::NotSquare);
         }
 
-        if opts.algorithm == QRAlgorithm::Symmetric && !(diff_symm(m.view()) < opts.eps.sqrt()) {
-            return Err(QRError::NotSymmetric);
+        let symmetric = matches!(
+            opts.algorithm,
+            QRAlgorithm::Symmetric
+                | QRAlgorithm::DivideAndConquer
+                | QRAlgorithm::Jacobi
+                | QRAlgorithm::Mrrr
+        );
+        if symmetric {
+            let diff = diff_symm(m.view());
+            if diff.is_nan() || diff >= opts.eps.sqrt() {
+                return Err(QRError::NotSymmetric);
+            }
         }
     }
 
@@ -49,10 +62,33 @@ pub fn schur_form_inplace_opts(mut m: MatrixViewMut, opts: &QROptions) -> Result
             hessenberg_form(m.view_mut(), u.view_mut(), opts);
             qr_algorithm_symmetric(m.view_mut(), u.view_mut(), opts);
         }
+        QRAlgorithm::DivideAndConquer => {
+            hessenberg_form(m.view_mut(), u.view_mut(), opts);
+            if !divide_and_conquer_symmetric(m.view_mut(), u.view_mut(), opts) {
+                return Err(QRError::ConvergenceFailed);
+            }
+        }
+        QRAlgorithm::Jacobi => {
+            let (_, converged) = jacobi_eigen(m.view_mut(), u.view_mut(), opts);
+            if !converged {
+                return Err(QRError::ConvergenceFailed);
+            }
+        }
+        QRAlgorithm::Mrrr => {
+            hessenberg_form(m.view_mut(), u.view_mut(), opts);
+            if !mrrr_symmetric(m.view_mut(), u.view_mut(), opts) {
+                return Err(QRError::ConvergenceFailed);
+            }
+        }
     }
 
     if opts.zero_entries {
+        // the small eigenvalues computed by Jacobi are accurate, only the off-diagonal part is zeroed
+        let diag = m.diag().into_owned();
         zero_subeps_entries(m.view_mut(), opts.eps);
+        if opts.algorithm == QRAlgorithm::Jacobi {
+            m.diag_mut().assign(&diag);
+        }
     }
 
     if opts.do_safety_checks {
@@ -61,7 +97,7 @@ pub fn schur_form_inplace_opts(mut m: MatrixViewMut, opts: &QROptions) -> Result
             _ => diff_triag(m.view()),
         };
 
-        if !(diff < opts.eps.sqrt()) {
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
             return Err(QRError::ConvergenceFailed);
         }
     }
@@ -96,3 +132,81 @@ pub fn schur_form_opts(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Matri
 pub fn schur_form(m: MatrixView) -> Result<(Matrix, Matrix)> {
     schur_form_opts(m, &DEFAULT_OPTS)
 }
+
+/// Reorders the (real) Schur form of a matrix in-place, accepts options
+///
+/// Outputs the dimension of the leading invariant subspace, i.e. the number of selected eigenvalues.
+///
+/// Moves the selected eigenvalues to the top-left corner of `T` with orthogonal similarity transformations, updating `U` so that `A = U T U^T` still holds.
+/// `select` has one entry per diagonal entry of `T`; selecting either of the two eigenvalues of a `2 by 2` block selects the whole block.
+/// The relative order of the selected eigenvalues is preserved.
+///
+/// Accepts a pair `(T, U)` as returned by `schur_form_opts`.
+/// Fails if two blocks to be swapped have too close eigenvalues.
+/// Performs O(n^3) operations at most.
+pub fn reorder_schur_inplace_opts(
+    mut t: MatrixViewMut,
+    mut u: MatrixViewMut,
+    select: &[bool],
+    opts: &QROptions,
+) -> Result<usize> {
+    if opts.do_safety_checks {
+        if !finite_entries(t.view()) || !finite_entries(u.view()) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !t.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if u.shape() != t.shape() || select.len() != t.shape()[0] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    let m = crate::implementation::reorder::reorder_schur(t.view_mut(), u.view_mut(), select)
+        .ok_or(QRError::ReorderingFailed)?;
+    if opts.zero_entries {
+        zero_subeps_entries(t.view_mut(), opts.eps);
+    }
+
+    Ok(m)
+}
+
+/// Reorders the (real) Schur form of a matrix in-place
+///
+/// Outputs the number of selected eigenvalues.
+/// Uses default options.
+/// See `reorder_schur_inplace_opts`.
+pub fn reorder_schur_inplace(t: MatrixViewMut, u: MatrixViewMut, select: &[bool]) -> Result<usize> {
+    reorder_schur_inplace_opts(t, u, select, &DEFAULT_OPTS)
+}
+
+/// Reorders the (real) Schur form of a matrix, accepts options
+///
+/// Outputs `(T, U, m)` where `m` is the number of selected eigenvalues.
+/// See `reorder_schur_inplace_opts`.
+pub fn reorder_schur_opts(
+    t: MatrixView,
+    u: MatrixView,
+    select: &[bool],
+    opts: &QROptions,
+) -> Result<(Matrix, Matrix, usize)> {
+    let mut t = t.into_owned();
+    let mut u = u.into_owned();
+    let m = reorder_schur_inplace_opts(t.view_mut(), u.view_mut(), select, opts)?;
+    Ok((t, u, m))
+}
+
+/// Reorders the (real) Schur form of a matrix
+///
+/// Outputs `(T, U, m)`.
+/// Uses default options.
+/// See `reorder_schur_inplace_opts`.
+pub fn reorder_schur(
+    t: MatrixView,
+    u: MatrixView,
+    select: &[bool],
+) -> Result<(Matrix, Matrix, usize)> {
+    reorder_schur_opts(t, u, select, &DEFAULT_OPTS)
+}
@@ -26,3 +26,61 @@ pub fn svd_opts(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Vector, Matr
 pub fn svd(m: MatrixView) -> Result<(Matrix, Vector, Matrix)> {
     svd_opts(m, &SYMMETRIC_OPTS)
 }
+
+/// Computes the singular values of a matrix, accepts options
+///
+/// Outputs the `min(m, n)` singular values of an `m by n` matrix sorted in the descending order.
+///
+/// Reduces the matrix to the upper bidiagonal form `A = Q B P^T` with Householder reflections and computes the singular values of `B`
+/// with the differential qd algorithm with shifts (dqds), which finds them to high relative accuracy, small ones included;
+/// the accuracy of the result with respect to `A` is that of the reduction. Singular vectors are not formed.
+/// `opts.algorithm` and `opts.accumulate_sim_transforms` are ignored.
+///
+/// Accepts any matrix with finite entries.
+/// Fails with `ConvergenceFailed` if dqds does not converge.
+/// Performs O(n^3) operations for the reduction and O(n^2) operations for the singular values.
+pub fn singular_values_opts(m: MatrixView, opts: &QROptions) -> Result<Vector> {
+    if opts.do_safety_checks && !finite_entries(m.view()) {
+        return Err(QRError::NotFinite);
+    }
+
+    crate::implementation::svd::singular_values(m).ok_or(QRError::ConvergenceFailed)
+}
+
+/// Computes the singular values of a matrix
+///
+/// Uses the default symmetric options.
+/// See `singular_values_opts`.
+pub fn singular_values(m: MatrixView) -> Result<Vector> {
+    singular_values_opts(m, &SYMMETRIC_OPTS)
+}
+
+/// Computes the polar decomposition of a matrix, accepts options
+///
+/// Outputs `(U, H)`.
+///
+/// The polar decomposition of an `m by n` matrix is a decomposition `A = U H` where `H` is symmetric positive semidefinite
+/// and `U` has orthonormal columns (if `m >= n`) or orthonormal rows (if `m < n`).
+/// `H = (A^T A)^(1/2)` is always unique; `U` is unique if `A` has full rank, and is the orthogonal matrix closest to `A`.
+///
+/// Uses the SVD decomposition `A = W S V^T`: `U = W V^T` and `H = V S V^T`, truncated to `min(m, n)` singular values.
+/// For rank-deficient `A`, `U` pairs the null singular vectors arbitrarily but keeps orthonormal columns (rows).
+///
+/// Accepts any matrix with finite entries.
+/// Performs O(n^3) operations.
+pub fn polar_decomposition_opts(m: MatrixView, opts: &QROptions) -> Result<(Matrix, Matrix)> {
+    if opts.do_safety_checks && !finite_entries(m.view()) {
+        return Err(QRError::NotFinite);
+    }
+
+    Ok(crate::implementation::svd::polar_decomposition(m, opts))
+}
+
+/// Computes the polar decomposition of a matrix
+///
+/// Outputs `(U, H)`.
+/// Uses the default symmetric options.
+/// See `polar_decomposition_opts`.
+pub fn polar_decomposition(m: MatrixView) -> Result<(Matrix, Matrix)> {
+    polar_decomposition_opts(m, &SYMMETRIC_OPTS)
+}
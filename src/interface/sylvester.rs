@@ -0,0 +1,61 @@
+use crate::implementation::checks::{finite_entries, frob_norm};
+use crate::implementation::sylvester::{bartels_stewart, sep_estimate};
+use crate::*;
+
+/// Solves the Sylvester equation `A X + X B = C`, accepts options
+///
+/// Outputs `(X, sep)` where `sep` is an estimate of the separation of `A` and `-B`,
+/// the smallest singular value of the operator `X -> A X + X B`.
+///
+/// The equation has a unique solution if and only if `A` and `-B` have no common eigenvalues.
+/// Uses the Bartels-Stewart algorithm: `A = U S U^T` and `B = V T V^T` are reduced to real Schur form (see `schur_form_inplace_opts`),
+/// the quasi-triangular equation `S Y + Y T = U^T C V` is solved block by block, one `1 by 1`, `2 by 2` or `4 by 4` system per pair of diagonal blocks,
+/// and `X = U Y V^T`. Transformations are always accumulated.
+/// A small `sep` relative to `|A| + |B|` indicates an ill-conditioned equation; `QRError::Singular` is returned if it is below roundoff.
+///
+/// Accepts square `A` (`m by m`), square `B` (`n by n`) and `C` (`m by n`) with finite entries.
+/// Performs O(m^3 + n^3) operations.
+pub fn sylvester_opts(
+    a: MatrixView,
+    b: MatrixView,
+    c: MatrixView,
+    opts: &QROptions,
+) -> Result<(Matrix, f64)> {
+    if opts.do_safety_checks {
+        if !finite_entries(a) || !finite_entries(b) || !finite_entries(c) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !a.is_square() || !b.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if c.shape() != [a.shape()[0], b.shape()[0]] {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    let schur_opts = QROptions {
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let (s, u) = schur_form_opts(a, &schur_opts)?;
+    let (t, v) = schur_form_opts(b, &schur_opts)?;
+
+    let sep = sep_estimate(s.view(), t.view(), 1.);
+    if sep.is_nan() || sep <= f64::EPSILON * (frob_norm(a) + frob_norm(b)) {
+        return Err(QRError::Singular);
+    }
+
+    let x = bartels_stewart(s.view(), u.view(), t.view(), v.view(), c, 1.);
+    Ok((x, sep))
+}
+
+/// Solves the Sylvester equation `A X + X B = C`
+///
+/// Outputs `(X, sep)`.
+/// Uses default options.
+/// See `sylvester_opts`.
+pub fn sylvester(a: MatrixView, b: MatrixView, c: MatrixView) -> Result<(Matrix, f64)> {
+    sylvester_opts(a, b, c, &DEFAULT_OPTS)
+}
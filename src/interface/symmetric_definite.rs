@@ -0,0 +1,71 @@
+use crate::implementation::checks::{diff_symm, finite_entries};
+use crate::implementation::cholesky::cholesky;
+use crate::implementation::common::sort_diagonal_values;
+use crate::implementation::symmetric_definite::*;
+use crate::*;
+
+/// Solves the symmetric-definite generalized eigenvalue problem, accepts options
+///
+/// Outputs `(L, X)` where `L` is a vector of eigenvalues sorted in the descending order and columns of `X` are the corresponding eigenvectors.
+///
+/// For symmetric `K` and symmetric positive definite `M` the problem `Kx = lMx` has real eigenvalues `l`
+/// and the eigenvectors can be chosen `M`-orthonormal, i.e. `X^T M X = I` and `X^T K X = diag(L)`.
+///
+/// Computes the Cholesky decomposition `M = C C^T`, solves the standard symmetric problem for `C^-1 K C^-T`
+/// with the symmetric QR algorithm (`opts.algorithm` is ignored) and transforms the eigenvectors back with `C^-T`.
+///
+/// Accepts symmetric `K` and symmetric positive definite `M` of equal size with finite entries.
+/// Fails with `NotPositiveDefinite` if the Cholesky decomposition of `M` breaks down.
+/// Performs O(n^3) operations.
+pub fn symmetric_definite_eigen_opts(
+    k: MatrixView,
+    m: MatrixView,
+    opts: &QROptions,
+) -> Result<(Vector, Matrix)> {
+    if opts.do_safety_checks {
+        if !finite_entries(k) || !finite_entries(m) {
+            return Err(QRError::NotFinite);
+        }
+
+        if !k.is_square() {
+            return Err(QRError::NotSquare);
+        }
+    }
+
+    if k.shape() != m.shape() {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    if opts.do_safety_checks {
+        let diff = diff_symm(k) + diff_symm(m);
+        if diff.is_nan() || diff >= opts.eps.sqrt() {
+            return Err(QRError::NotSymmetric);
+        }
+    }
+
+    let mut l = m.into_owned();
+    cholesky(l.view_mut()).map_err(QRError::NotPositiveDefinite)?;
+
+    let mut t = symmetric_definite_reduction(k, l.view());
+    let sym_opts = QROptions {
+        algorithm: QRAlgorithm::Symmetric,
+        accumulate_sim_transforms: true,
+        ..opts.clone()
+    };
+    let mut u = schur_form_inplace_opts(t.view_mut(), &sym_opts)?;
+
+    let mut z = t.diag().into_owned();
+    sort_diagonal_values(z.view_mut(), u.view_mut());
+    symmetric_definite_back_transform(l.view(), u.view_mut());
+
+    Ok((z, u))
+}
+
+/// Solves the symmetric-definite generalized eigenvalue problem
+///
+/// Outputs `(L, X)`.
+/// Uses the default symmetric options.
+/// See `symmetric_definite_eigen_opts`.
+pub fn symmetric_definite_eigen(k: MatrixView, m: MatrixView) -> Result<(Vector, Matrix)> {
+    symmetric_definite_eigen_opts(k, m, &SYMMETRIC_OPTS)
+}
@@ -0,0 +1,105 @@
+use crate::implementation::common::sort_diagonal_values;
+use crate::implementation::divide_conquer::divide_and_conquer;
+use crate::implementation::mrrr::mrrr;
+use crate::implementation::qr_symmetric::qr_algorithm_tridiagonal;
+use crate::*;
+
+use std::cmp::Ordering;
+
+#[inline]
+fn check_tridiagonal(d: VectorView, e: VectorView, opts: &QROptions) -> Result<()> {
+    if opts.do_safety_checks && !d.iter().chain(e.iter()).all(|x| x.is_finite()) {
+        return Err(QRError::NotFinite);
+    }
+
+    if e.shape()[0] + 1 != d.shape()[0].max(1) {
+        return Err(QRError::DimensionMismatch);
+    }
+
+    Ok(())
+}
+
+/// Computes the eigenvalues of a symmetric tridiagonal matrix, accepts options
+///
+/// Outputs the eigenvalues sorted in descending order.
+///
+/// The matrix is given by its diagonal `d` (length `n`) and off-diagonal `e` (length `n - 1`).
+/// Runs the implicit shift symmetric QR algorithm directly on `d` and `e`, without forming the matrix or reducing it to Hessenberg form;
+/// `opts.algorithm` and `opts.accumulate_sim_transforms` are ignored.
+///
+/// Accepts any finite `d` and `e` of matching lengths.
+/// Performs O(n^2) operations and uses O(n) memory.
+pub fn tridiagonal_eigenvalues_opts(
+    d: VectorView,
+    e: VectorView,
+    opts: &QROptions,
+) -> Result<Vector> {
+    check_tridiagonal(d, e, opts)?;
+
+    let (mut z, mut e) = (d.into_owned(), e.into_owned());
+    if !qr_algorithm_tridiagonal(z.view_mut(), e.view_mut(), None, opts) && opts.do_safety_checks {
+        return Err(QRError::ConvergenceFailed);
+    }
+
+    z.as_slice_mut()
+        .unwrap()
+        .sort_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+    Ok(z)
+}
+
+/// Computes the eigenvalues of a symmetric tridiagonal matrix
+///
+/// Uses the default symmetric options.
+/// See `tridiagonal_eigenvalues_opts`.
+pub fn tridiagonal_eigenvalues(d: VectorView, e: VectorView) -> Result<Vector> {
+    tridiagonal_eigenvalues_opts(d, e, &SYMMETRIC_OPTS)
+}
+
+/// Computes the eigendecomposition of a symmetric tridiagonal matrix, accepts options
+///
+/// Outputs `(z, U)` with eigenvalues `z` sorted in descending order and the corresponding orthonormal eigenvectors in the columns of `U`,
+/// so that `T = U diag(z) U^T`.
+///
+/// See `tridiagonal_eigenvalues_opts`; transformations are always accumulated.
+/// If `opts.algorithm == QRAlgorithm::DivideAndConquer`, Cuppen's divide-and-conquer algorithm is used instead of the QR algorithm;
+/// if `opts.algorithm == QRAlgorithm::Mrrr`, the MRRR algorithm is used, which needs only O(n^2) operations besides forming `U`
+/// unless some cluster has to fall back to inverse iteration (see `QRAlgorithm`).
+/// Performs O(n^3) operations and uses O(n^2) memory.
+pub fn tridiagonal_eigen_opts(
+    d: VectorView,
+    e: VectorView,
+    opts: &QROptions,
+) -> Result<(Vector, Matrix)> {
+    check_tridiagonal(d, e, opts)?;
+
+    let (mut z, mut e) = (d.into_owned(), e.into_owned());
+    let mut u = match opts.algorithm {
+        QRAlgorithm::DivideAndConquer => {
+            divide_and_conquer(z.view_mut(), e.view(), opts).ok_or(QRError::ConvergenceFailed)?
+        }
+        QRAlgorithm::Mrrr => {
+            mrrr(z.view_mut(), e.view(), opts).ok_or(QRError::ConvergenceFailed)?
+        }
+        _ => {
+            let mut u = Matrix::eye(z.shape()[0]);
+            if !qr_algorithm_tridiagonal(z.view_mut(), e.view_mut(), Some(u.view_mut()), opts)
+                && opts.do_safety_checks
+            {
+                return Err(QRError::ConvergenceFailed);
+            }
+            u
+        }
+    };
+
+    sort_diagonal_values(z.view_mut(), u.view_mut());
+    Ok((z, u))
+}
+
+/// Computes the eigendecomposition of a symmetric tridiagonal matrix
+///
+/// Outputs `(z, U)`.
+/// Uses the default symmetric options.
+/// See `tridiagonal_eigen_opts`.
+pub fn tridiagonal_eigen(d: VectorView, e: VectorView) -> Result<(Vector, Matrix)> {
+    tridiagonal_eigen_opts(d, e, &SYMMETRIC_OPTS)
+}
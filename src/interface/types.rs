@@ -25,12 +25,87 @@ pub type Complex = num::Complex<f64>;
 /// - `Hessenberg` --- QR algorithm on Hessenberg matrices; O(n^3) reduction + O(n^2) operations per step, but same issues as the naive algorithm.
 /// - `Francis` --- implicit double-shift QR algorithm; O(n^3) reduction + O(n^2) operations per step, usually linear covergence rate, almost always converges (but there are known counterexamples).
 /// - `Symmetric` --- implicit shifit QR algorithm for symmetric matrices; O(n^3 reduction) + O(n^2) operations per step, usually linear covergence rate, always converges.
+/// - `DivideAndConquer` --- Cuppen's divide-and-conquer algorithm for symmetric matrices; O(n^3) reduction + O(n^3) worst case, but usually much faster than `Symmetric` when all eigenvectors are needed thanks to deflation.
+/// - `Jacobi` --- cyclic Jacobi algorithm for symmetric matrices; no reduction, O(n^3) operations per sweep (`opts.iterations` limits the sweeps), quadratic convergence, slower than `Symmetric` but computes small eigenvalues of graded positive definite matrices to high relative accuracy; `opts.zero_entries` leaves the diagonal untouched, so they are kept.
+/// - `Mrrr` --- multiple relatively robust representations for symmetric matrices; O(n^3) reduction + O(n^2) operations for all eigenpairs, eigenvalues by dqds and eigenvectors by twisted factorizations without reorthogonalization. Clusters that the representation tree cannot resolve fall back to inverse iteration with reorthogonalization, which costs O(nk^2) for a cluster of `k` eigenvalues, so up to O(n^3) in the worst case.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum QRAlgorithm {
     Naive,
     Hessenberg,
     Francis,
     Symmetric,
+    DivideAndConquer,
+    Jacobi,
+    Mrrr,
+}
+
+/// Selection of eigenvalues of a symmetric matrix
+///
+/// - `Interval(a, b)` --- eigenvalues in the half-open interval `(a, b]`.
+/// - `Indices(k, l)` --- eigenvalues with indices `k..l` in the ascending order, i.e. `Indices(0, 10)` selects the 10 smallest ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EigenvalueRange {
+    Interval(f64, f64),
+    Indices(usize, usize),
+}
+
+/// Convergence report of the Jacobi algorithms
+///
+/// - `sweeps` --- number of sweeps over all off-diagonal pairs performed, including the last one with no rotations.
+/// - `off_norm` --- Frobenius norm of the off-diagonal part of the result scaled by its diagonal, `D^-1/2 (T - D) D^-1/2` with `D = |diag(T)|`;
+///   for the SVD `T = (AV)^T (AV)`, i.e. the cosines of angles between the columns of `AV`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JacobiInfo {
+    pub sweeps: usize,
+    pub off_norm: f64,
+}
+
+/// Eigenvalues computed by the Lanczos algorithm
+///
+/// - `Largest` --- the largest eigenvalues.
+/// - `Smallest` --- the smallest eigenvalues.
+///
+/// The eigenvalues closest to a shift are computed by `lanczos_shift_invert_operator_opts`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LanczosTarget {
+    Largest,
+    Smallest,
+}
+
+/// Reorthogonalization strategies of the Lanczos algorithm
+///
+/// - `Full` --- every new basis vector is orthogonalized against the whole basis; O(nm) operations per step for basis size `m`, orthogonal to working precision.
+/// - `Selective` --- the loss of orthogonality is estimated by the omega-recurrence in O(m^2) operations per step, and only when it reaches `sqrt(eps)`
+///   the new basis vector (and the one after it) is orthogonalized against the Ritz vectors that have converged to `sqrt(eps)` and the vectors kept on restart;
+///   most steps cost O(n) besides the product, keeps the basis orthogonal to about `sqrt(eps)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reorthogonalization {
+    Full,
+    Selective,
+}
+
+/// Convergence report of the vector iterations and the Lanczos algorithm
+///
+/// - `iterations` --- number of steps performed, or restarts for the Lanczos algorithm.
+/// - `residual` --- residual `|A x - l x|` of the computed eigenpair `(l, x)` with `|x| = 1`, the largest one over all computed eigenpairs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterationInfo {
+    pub iterations: usize,
+    pub residual: f64,
+}
+
+/// Gauss quadrature rules
+///
+/// - `Legendre` --- weight `1` on `[-1, 1]`.
+/// - `Hermite` --- weight `exp(-x^2)` on `(-inf, inf)`.
+/// - `Laguerre(alpha)` --- weight `x^alpha exp(-x)` on `[0, inf)`, `alpha > -1`.
+/// - `Jacobi(alpha, beta)` --- weight `(1 - x)^alpha (1 + x)^beta` on `[-1, 1]`, `alpha, beta > -1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuadratureRule {
+    Legendre,
+    Hermite,
+    Laguerre(f64),
+    Jacobi(f64, f64),
 }
 
 /// Algorithm options
@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::tests::common::random_symmetric;
+use crate::*;
+
+use ndarray::array;
+
+const EPS: f64 = 1e-4;
+
+fn all_eigenvalues(a: MatrixView) -> Vec<f64> {
+    let (t, _) = schur_form_opts(a, &SYMMETRIC_OPTS).unwrap();
+    let mut z = t.diag().to_vec();
+    z.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    z
+}
+
+fn random_check_range(sz: usize) {
+    let a = random_symmetric(sz);
+    let all = all_eigenvalues(a.view());
+
+    let (k, l) = (sz / 3, sz - sz / 3);
+    let (z, x) = symmetric_eigen_range(a.view(), EigenvalueRange::Indices(k, l)).unwrap();
+    assert_eq!(z.len(), l - k);
+    assert!(z
+        .iter()
+        .zip(all[k..l].iter())
+        .all(|(a, b)| (a - b).abs() < EPS * (1. + b.abs())));
+    assert!(diff_unit(x.t().dot(&x).view()) < EPS);
+    assert!(
+        frob_norm((a.dot(&x) - x.dot(&Matrix::from_diag(&z))).view()) < EPS * frob_norm(a.view())
+    );
+
+    let lo = if k > 0 {
+        (all[k - 1] + all[k]) / 2.
+    } else {
+        all[0] - 1.
+    };
+    let hi = if l < sz {
+        (all[l - 1] + all[l]) / 2.
+    } else {
+        all[sz - 1] + 1.
+    };
+    let values = symmetric_eigenvalues_range(a.view(), EigenvalueRange::Interval(lo, hi)).unwrap();
+    let expected: Vec<f64> = all
+        .iter()
+        .cloned()
+        .filter(|x| *x > lo && *x <= hi)
+        .collect();
+    assert_eq!(values.len(), expected.len());
+    assert!(values
+        .iter()
+        .zip(expected.iter())
+        .all(|(a, b)| (a - b).abs() < EPS * (1. + b.abs())));
+}
+
+#[test]
+fn test_eigen_range() {
+    for sz in [1, 2, 3, 5, 10, 20, 50] {
+        for _ in 0..5 {
+            random_check_range(sz);
+        }
+    }
+}
+
+#[test]
+fn test_eigen_range_clustered() {
+    // the identity and a matrix with few distinct eigenvalues need reorthogonalization
+    let n = 30;
+    let (q, _) = qr_decomposition(random_symmetric(n).view()).unwrap();
+    let d: Vector = (0..n).map(|i| (i % 3) as f64).collect();
+    for a in [Matrix::eye(n), q.dot(&Matrix::from_diag(&d)).dot(&q.t())] {
+        let (z, x) = symmetric_eigen_range(a.view(), EigenvalueRange::Indices(0, n)).unwrap();
+        assert!(diff_unit(x.t().dot(&x).view()) < EPS);
+        assert!(
+            frob_norm((a.dot(&x) - x.dot(&Matrix::from_diag(&z))).view())
+                < EPS * frob_norm(a.view())
+        );
+    }
+
+    let (z, x) = symmetric_eigen_range(
+        q.dot(&Matrix::from_diag(&d)).dot(&q.t()).view(),
+        EigenvalueRange::Interval(0.5, 1.5),
+    )
+    .unwrap();
+    assert_eq!(z.len(), n / 3);
+    assert!(z.iter().all(|x| (x - 1.).abs() < EPS));
+    assert!(diff_unit(x.t().dot(&x).view()) < EPS);
+}
+
+#[test]
+fn test_eigen_range_known() {
+    // The second difference matrix has eigenvalues 2 - 2 cos(k pi / (n + 1))
+    let n = 200;
+    let mut a = 2. * Matrix::eye(n);
+    for k in 1..n {
+        a[[k, k - 1]] = -1.;
+        a[[k - 1, k]] = -1.;
+    }
+
+    let z = symmetric_eigenvalues_range(a.view(), EigenvalueRange::Indices(0, 10)).unwrap();
+    for (i, x) in z.iter().enumerate() {
+        let expected = 2. - 2. * ((i + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+        assert!((x - expected).abs() < 1e-12);
+    }
+
+    let (z, x) = symmetric_eigen_range(a.view(), EigenvalueRange::Indices(n - 2, n)).unwrap();
+    assert!(frob_norm((a.dot(&x) - x.dot(&Matrix::from_diag(&z))).view()) < EPS);
+    assert!(x.column(0).dot(&x.column(1)).abs() < EPS);
+
+    let one = array![[1.]];
+    assert!(
+        symmetric_eigenvalues_range(one.view(), EigenvalueRange::Interval(1., 2.))
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        symmetric_eigenvalues_range(one.view(), EigenvalueRange::Indices(0, 2)),
+        Err(QRError::InvalidParameter)
+    );
+    assert_eq!(
+        symmetric_eigenvalues_range(one.view(), EigenvalueRange::Interval(2., 1.)),
+        Err(QRError::InvalidParameter)
+    );
+}
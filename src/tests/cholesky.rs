@@ -0,0 +1,58 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::tests::common::random_spd;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_cholesky(sz: usize) {
+    let a = random_spd(sz);
+    let l = cholesky(a.view()).unwrap();
+
+    assert!(diff_rel(a.view(), l.dot(&l.t()).view()) < EPS);
+    for i in 0..sz {
+        assert!(l[[i, i]] > 0.);
+        for j in i + 1..sz {
+            assert_eq!(l[[i, j]], 0.);
+        }
+    }
+
+    let b = Array::random([sz, 3], Uniform::new(-10., 10.));
+    let x = cholesky_solve(l.view(), b.view()).unwrap();
+    assert!(diff_rel(b.view(), a.dot(&x).view()) < EPS);
+}
+
+#[test]
+fn test_cholesky() {
+    for sz in [1, 2, 3, 5, 10, 20, 50] {
+        for _ in 0..10 {
+            random_check_cholesky(sz);
+        }
+    }
+}
+
+#[test]
+fn test_cholesky_not_positive_definite() {
+    let a = ndarray::array![[4., 2., 0.], [2., 1., 0.], [0., 0., 1.]];
+    assert_eq!(cholesky(a.view()), Err(QRError::NotPositiveDefinite(1)));
+
+    let a = ndarray::array![[-1., 0.], [0., 1.]];
+    assert_eq!(cholesky(a.view()), Err(QRError::NotPositiveDefinite(0)));
+}
+
+#[test]
+fn test_triangular_solve() {
+    let t = ndarray::array![[2., 0., 0.], [1., 3., 0.], [-1., 2., 4.]];
+    let b = ndarray::array![[2.], [7.], [15.]];
+
+    let x = triangular_solve(t.view(), b.view(), true).unwrap();
+    assert!(diff_rel(ndarray::array![[1.], [2.], [3.]].view(), x.view()) < EPS);
+
+    let x = triangular_solve(t.t(), b.view(), false).unwrap();
+    assert!(diff_rel(b.view(), t.t().dot(&x).view()) < EPS);
+}
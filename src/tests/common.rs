@@ -0,0 +1,22 @@
+#![cfg(test)]
+
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+pub fn random_symmetric(sz: usize) -> Matrix {
+    let mut a = Array::random([sz, sz], Uniform::new(-10., 10.));
+    for i in 0..sz {
+        for j in 0..i {
+            a[[i, j]] = a[[j, i]]
+        }
+    }
+    a
+}
+
+pub fn random_spd(sz: usize) -> Matrix {
+    let b = Array::random([sz, sz], Uniform::new(-10., 10.));
+    b.dot(&b.t()) + Matrix::eye(sz)
+}
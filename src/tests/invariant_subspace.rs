@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::implementation::common::extract_eigenvalues;
+use crate::*;
+
+use ndarray::{s, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_reorder(sz: usize) {
+    let a = Array::random([sz, sz], Uniform::new(-10., 10.));
+    let (t, u) = schur_form(a.view()).unwrap();
+    let eigs = extract_eigenvalues(t.view());
+    let select: Vec<bool> = (0..sz).map(|i| t[[i, i]] > 0.).collect();
+    let (t, u, m) = reorder_schur(t.view(), u.view(), &select).unwrap();
+
+    assert_eq!(m, eigs.iter().filter(|e| e.re > 0.).count());
+    assert!(diff_subtriag(t.view()) < EPS);
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(diff_rel(a.view(), u.dot(&t).dot(&u.t()).view()) < EPS);
+    for i in 0..sz {
+        assert_eq!(t[[i, i]] > 0., i < m);
+    }
+}
+
+#[test]
+fn test_reorder_schur() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        for _ in 0..10 {
+            random_check_reorder(sz);
+        }
+    }
+}
+
+#[test]
+fn test_invariant_subspace() {
+    let a = Array::random([10, 10], Uniform::new(-10., 10.));
+    let (t, u) = schur_form(a.view()).unwrap();
+    let select: Vec<bool> = (0..10).map(|i| i % 3 == 0).collect();
+    let (x, s, sep) = invariant_subspace(t.view(), u.view(), &select).unwrap();
+
+    let m = x.shape()[1];
+    let h = x.t().dot(&a).dot(&x);
+    assert!(diff_rel(a.dot(&x).view(), x.dot(&h).view()) < EPS);
+    assert!(diff_rel(x.t().dot(&x).view(), Matrix::eye(m).view()) < EPS);
+    assert!(s > 0. && s <= 1.);
+    assert!(sep > 0.);
+}
+
+#[test]
+fn test_invariant_subspace_triangular() {
+    let t = ndarray::array![[1., 1.], [0., 1. + 1e-6]];
+    let (x, s, sep) = invariant_subspace(t.view(), Matrix::eye(2).view(), &[false, true]).unwrap();
+
+    assert!(
+        diff_rel(
+            t.dot(&x).view(),
+            (x.slice(s![.., 0..1]).to_owned() * (1. + 1e-6)).view()
+        ) < EPS
+    );
+    assert!(s < 1e-4);
+    assert!(sep < 1e-4);
+}
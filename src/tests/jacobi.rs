@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::tests::common::random_symmetric;
+use crate::*;
+
+use ndarray::{Array, Axis};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+// D H D with a well conditioned positive definite H and D = diag(2^(-4i)) spanning 12 orders of magnitude
+fn graded(sz: usize) -> (Matrix, Matrix, Vector) {
+    let b = Array::random([sz, sz], Uniform::new(-1., 1.));
+    let h = b.dot(&b.t()) / sz as f64 + Matrix::eye(sz);
+    let d: Vector = (0..sz).map(|i| 2f64.powi(-4 * i as i32)).collect();
+    let dd = d.clone().insert_axis(Axis(1));
+    (&h * &dd * dd.t(), h, d)
+}
+
+fn random_check_jacobi_eigen(sz: usize) {
+    let a = random_symmetric(sz);
+    let (z, u, info) = jacobi_eigen(a.view()).unwrap();
+
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(diff_rel(a.view(), u.dot(&Matrix::from_diag(&z)).dot(&u.t()).view()) < EPS);
+    assert!(z.iter().zip(z.iter().skip(1)).all(|(a, b)| a >= b));
+    assert!(info.sweeps <= 20);
+    assert!(info.off_norm < EPS);
+
+    let opts = QROptions {
+        algorithm: QRAlgorithm::Jacobi,
+        ..SYMMETRIC_OPTS
+    };
+    let (t, u) = schur_form_opts(a.view(), &opts).unwrap();
+    assert!(diff_triag(t.view()) < EPS);
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(diff_rel(a.view(), u.dot(&t).dot(&u.t()).view()) < EPS);
+}
+
+#[test]
+fn test_jacobi_eigen() {
+    for sz in [1, 2, 3, 5, 10, 20, 50] {
+        for _ in 0..5 {
+            random_check_jacobi_eigen(sz);
+        }
+    }
+}
+
+#[test]
+fn test_jacobi_eigen_graded() {
+    // the small eigenvalues of A are the reciprocals of the large eigenvalues of A^-1 = D^-1 H^-1 D^-1
+    let sz = 10;
+    let (a, h, d) = graded(sz);
+    let (z, _, _) = jacobi_eigen(a.view()).unwrap();
+
+    let di = d.mapv(|x| 1. / x).insert_axis(Axis(1));
+    let ai = &inverse(h.view()).unwrap() * &di * di.t();
+    let (t, _) = schur_form_opts(ai.view(), &SYMMETRIC_OPTS).unwrap();
+    let mut zi = t.diag().to_vec();
+    zi.sort_by(|x, y| y.partial_cmp(x).unwrap());
+
+    // the Schur form keeps them as well
+    let opts = QROptions {
+        algorithm: QRAlgorithm::Jacobi,
+        ..SYMMETRIC_OPTS
+    };
+    let (t, _) = schur_form_opts(a.view(), &opts).unwrap();
+    let mut zs = t.diag().to_vec();
+    zs.sort_by(|x, y| y.partial_cmp(x).unwrap());
+
+    for k in 0..3 {
+        let expected = 1. / zi[k];
+        assert!(((z[sz - 1 - k] - expected) / expected).abs() < EPS);
+        assert!(((zs[sz - 1 - k] - expected) / expected).abs() < EPS);
+    }
+}
+
+fn random_check_jacobi_svd(rows: usize, cols: usize) {
+    let a = Array::random([rows, cols], Uniform::new(-10., 10.));
+    let (u, s, vt, info) = jacobi_svd(a.view()).unwrap();
+
+    assert_eq!(s.len(), rows);
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(diff_unit(vt.view()) < EPS);
+    assert!(s.iter().zip(s.iter().skip(1)).all(|(a, b)| a >= b));
+    assert!(info.off_norm < EPS);
+
+    let mut sm = Matrix::zeros((rows, cols));
+    for i in 0..rows.min(cols) {
+        sm[[i, i]] = s[i];
+    }
+    assert!(diff_rel(a.view(), u.dot(&sm).dot(&vt).view()) < EPS);
+
+    let (_, expected, _) = svd(a.view()).unwrap();
+    assert!((&s - &expected).iter().all(|x| x.abs() < EPS * (1. + s[0])));
+}
+
+#[test]
+fn test_jacobi_svd() {
+    for (rows, cols) in [(1, 1), (2, 3), (3, 2), (5, 5), (10, 4), (4, 10), (20, 20)] {
+        for _ in 0..5 {
+            random_check_jacobi_svd(rows, cols);
+        }
+    }
+
+    // rank deficient
+    let b = Array::random([6, 2], Uniform::new(-1., 1.));
+    let a = b.dot(&b.t());
+    let (u, s, vt, _) = jacobi_svd(a.view()).unwrap();
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(s.iter().skip(2).all(|x| x.abs() < EPS));
+    assert!(diff_rel(a.view(), u.dot(&Matrix::from_diag(&s)).dot(&vt).view()) < EPS);
+}
+
+#[test]
+fn test_jacobi_svd_graded() {
+    // the smallest singular value of B D is the reciprocal of the largest one of D^-1 B^-1
+    let sz = 10;
+    let b = Array::random([sz, sz], Uniform::new(-1., 1.)) + 3. * Matrix::eye(sz);
+    let d: Vector = (0..sz).map(|i| 2f64.powi(-4 * i as i32)).collect();
+    let a = &b * &d.clone().insert_axis(Axis(0));
+    let (_, s, _, _) = jacobi_svd(a.view()).unwrap();
+
+    let ai = &inverse(b.view()).unwrap() * &d.mapv(|x| 1. / x).insert_axis(Axis(1));
+    let (_, si, _) = svd(ai.view()).unwrap();
+    let expected = 1. / si[0];
+    assert!(((s[sz - 1] - expected) / expected).abs() < EPS);
+}
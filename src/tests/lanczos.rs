@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use std::f64::consts::PI;
+
+const EPS: f64 = 1e-4;
+
+fn check_eigenpairs(op: &impl LinearOperator, z: VectorView, u: MatrixView, expected: &[f64]) {
+    let k = expected.len();
+    assert_eq!(u.shape(), &[op.shape().0, k]);
+    for (a, b) in z.iter().zip(expected.iter()) {
+        assert!((a - b).abs() < EPS * (1. + b.abs()));
+    }
+
+    let gram = u.t().dot(&u) - Matrix::eye(k);
+    assert!(gram.iter().all(|x| x.abs() < EPS));
+    for (x, l) in u.gencolumns().into_iter().zip(z.iter()) {
+        let r = op.apply(x) - *l * &x;
+        assert!(r.dot(&r).sqrt() < EPS * (1. + l.abs()));
+    }
+}
+
+fn laplacian(x: VectorView) -> Vector {
+    let n = x.len();
+    (0..n)
+        .map(|i| {
+            let left = if i > 0 { x[i - 1] } else { 0. };
+            let right = if i + 1 < n { x[i + 1] } else { 0. };
+            2. * x[i] - left - right
+        })
+        .collect()
+}
+
+#[test]
+fn test_lanczos_dense() {
+    for reorth in [Reorthogonalization::Full, Reorthogonalization::Selective] {
+        for (n, k) in [(5, 5), (30, 3), (200, 6)] {
+            let m = Array::random((n, n), Uniform::new(-1., 1.));
+            let (q, _) = qr_decomposition(m.view()).unwrap();
+            let mut d: Vec<f64> = (0..n).map(|i| i as f64 - (n / 3) as f64).collect();
+            let a = q
+                .dot(&Matrix::from_diag(&Vector::from(d.clone())))
+                .dot(&q.t());
+
+            let (z, u, _) =
+                lanczos_opts(&a, k, LanczosTarget::Smallest, reorth, &SYMMETRIC_OPTS).unwrap();
+            check_eigenpairs(&a, z.view(), u.view(), &d[..k]);
+
+            d.reverse();
+            let (z, u, _) = lanczos_opts(
+                &a.view(),
+                k,
+                LanczosTarget::Largest,
+                reorth,
+                &SYMMETRIC_OPTS,
+            )
+            .unwrap();
+            check_eigenpairs(&a, z.view(), u.view(), &d[..k]);
+        }
+    }
+}
+
+#[test]
+fn test_lanczos_operator() {
+    // The second difference matrix has eigenvalues 2 - 2 cos(k pi / (n + 1))
+    let n = 400;
+    let value = |k: usize| 2. - 2. * (k as f64 * PI / (n + 1) as f64).cos();
+    let op = SymmetricFnOperator::new(n, laplacian);
+
+    let (z, u, _) = lanczos(&op, 4, LanczosTarget::Largest).unwrap();
+    let expected: Vec<f64> = (0..4).map(|i| value(n - i)).collect();
+    check_eigenpairs(&op, z.view(), u.view(), &expected);
+
+    let (z, u, _) = lanczos_opts(
+        &op,
+        4,
+        LanczosTarget::Smallest,
+        Reorthogonalization::Selective,
+        &SYMMETRIC_OPTS,
+    )
+    .unwrap();
+    let expected: Vec<f64> = (1..5).map(value).collect();
+    check_eigenpairs(&op, z.view(), u.view(), &expected);
+}
+
+#[test]
+fn test_lanczos_shift_invert() {
+    let n = 200;
+    let value = |k: usize| 2. - 2. * (k as f64 * PI / (n + 1) as f64).cos();
+    let a = operator_matrix(&SymmetricFnOperator::new(n, laplacian));
+    let sigma = 1.01;
+    let mut all: Vec<f64> = (1..=n).map(value).collect();
+    all.sort_by(|a, b| (a - sigma).abs().partial_cmp(&(b - sigma).abs()).unwrap());
+    for reorth in [Reorthogonalization::Full, Reorthogonalization::Selective] {
+        let (z, u, _) =
+            lanczos_shift_invert_opts(a.view(), sigma, 3, reorth, &SYMMETRIC_OPTS).unwrap();
+        check_eigenpairs(&a, z.view(), u.view(), &all[..3]);
+    }
+
+    // Pairs of eigenvalues at the same distance from sigma, which must not be mixed
+    let d: Vector = (1..=50)
+        .flat_map(|j| [0.5 - 0.01 * j as f64, 0.5 + 0.01 * j as f64])
+        .collect();
+    let op = SymmetricFnOperator::new(d.len(), |x| &d * &x);
+    let (z, u, _) = lanczos_shift_invert_operator(&op, |b| &b / &(&d - 0.5), 0.5, 4).unwrap();
+    let mut sorted = z.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    check_eigenpairs(&op, z.view(), u.view(), &z.to_vec());
+    for (a, b) in sorted.iter().zip([0.48, 0.49, 0.51, 0.52].iter()) {
+        assert!((a - b).abs() < EPS);
+    }
+}
+
+#[test]
+fn test_lanczos_selective_ghosts() {
+    // Well separated dominant eigenvalues converge within a few steps,
+    // after which a basis without reorthogonalization picks up spurious copies of them
+    let n = 2000;
+    let d: Vector = (0..n)
+        .map(|i| {
+            if i < 3 {
+                100. - i as f64
+            } else {
+                (i as f64) / (n as f64)
+            }
+        })
+        .collect();
+    let op = SymmetricFnOperator::new(n, |x| &d * &x);
+
+    let (z, u, _) = lanczos_opts(
+        &op,
+        3,
+        LanczosTarget::Largest,
+        Reorthogonalization::Selective,
+        &SYMMETRIC_OPTS,
+    )
+    .unwrap();
+    check_eigenpairs(&op, z.view(), u.view(), &[100., 99., 98.]);
+}
+
+#[test]
+fn test_lanczos_failures() {
+    let a = array![[1., 2.], [3., 4.]];
+    assert_eq!(
+        lanczos(&a, 1, LanczosTarget::Largest),
+        Err(QRError::NotSymmetric)
+    );
+    assert_eq!(
+        lanczos(
+            &array![[1., 2., 3.], [4., 5., 6.]],
+            1,
+            LanczosTarget::Largest
+        ),
+        Err(QRError::NotSquare)
+    );
+
+    let a = array![[2., 1.], [1., 2.]];
+    assert_eq!(
+        lanczos(&a, 0, LanczosTarget::Largest),
+        Err(QRError::InvalidParameter)
+    );
+    assert_eq!(
+        lanczos(&a, 3, LanczosTarget::Smallest),
+        Err(QRError::InvalidParameter)
+    );
+    assert_eq!(
+        lanczos_shift_invert(a.view(), f64::NAN, 1),
+        Err(QRError::InvalidParameter)
+    );
+    // a solve that does not invert A - sigma I gives eigenpairs with large residuals
+    assert_eq!(
+        lanczos_shift_invert_operator(&a, |b| b.into_owned(), 0., 1),
+        Err(QRError::ConvergenceFailed)
+    );
+    assert_eq!(
+        lanczos(
+            &SymmetricFnOperator::new(2, |x| f64::NAN * &x),
+            1,
+            LanczosTarget::Largest
+        ),
+        Err(QRError::NotFinite)
+    );
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_least_squares(m: usize, n: usize) {
+    let a = Array::random([m, n], Uniform::new(-10., 10.));
+    let b = Array::random([m, 3], Uniform::new(-10., 10.));
+    let (x, r, rank) = least_squares(a.view(), b.view()).unwrap();
+
+    // Normal equations A^T (AX - B) = 0 hold for any least-squares solution
+    let res = a.dot(&x) - &b;
+    assert!(frob_norm(a.t().dot(&res).view()) < EPS * frob_norm(a.t().dot(&b).view()));
+    assert_eq!(rank, m.min(n));
+    for j in 0..3 {
+        assert!((r[j] - res.column(j).dot(&res.column(j)).sqrt()).abs() < EPS);
+    }
+}
+
+#[test]
+fn test_least_squares() {
+    for (m, n) in [(1, 1), (3, 2), (5, 3), (10, 10), (20, 5), (50, 10), (3, 5)] {
+        for _ in 0..10 {
+            random_check_least_squares(m, n);
+        }
+    }
+}
+
+#[test]
+fn test_least_squares_rank_deficient() {
+    let c = Array::random([20, 3], Uniform::new(-10., 10.));
+    let mut a = Matrix::zeros((20, 5));
+    a.slice_mut(ndarray::s![.., 0..3]).assign(&c);
+    a.column_mut(3).assign(&(&c.column(0) + &c.column(1)));
+    a.column_mut(4).assign(&(2. * &c.column(2)));
+
+    let b = Array::random(20, Uniform::new(-10., 10.));
+    let (x, r, rank) = least_squares_vector(a.view(), b.view()).unwrap();
+    assert_eq!(rank, 3);
+
+    let res = a.dot(&x) - &b;
+    assert!(a.t().dot(&res).iter().all(|y| y.abs() < EPS * 1e2));
+    assert!((r - res.dot(&res).sqrt()).abs() < EPS);
+
+    // Minimum-norm solution is orthogonal to the null space of A
+    let null = ndarray::array![[1., 1., 0., -1., 0.], [0., 0., 2., 0., -1.]];
+    assert!(null.dot(&x).iter().all(|y| y.abs() < EPS));
+}
+
+#[test]
+fn test_least_squares_exact() {
+    let a = ndarray::array![[1., 0.], [1., 1.], [1., 2.]];
+    let b = ndarray::array![1., 3., 5.];
+    let (x, r, rank) = least_squares_vector(a.view(), b.view()).unwrap();
+
+    assert_eq!(rank, 2);
+    assert!((x[0] - 1.).abs() < EPS && (x[1] - 2.).abs() < EPS);
+    assert!(r < EPS);
+}
+
+#[test]
+fn test_least_squares_ill_conditioned() {
+    // Full column rank, but the last diagonal entry of R is about 1e-6 relative to the first one
+    let a = ndarray::array![[1., 1.], [1., 1. + 1e-6], [1., 1.]];
+    let b = a.dot(&ndarray::array![1., 2.]);
+    let (x, r, rank) = least_squares_vector(a.view(), b.view()).unwrap();
+
+    assert_eq!(rank, 2);
+    assert!((x[0] - 1.).abs() < EPS && (x[1] - 2.).abs() < EPS);
+    assert!(r < EPS);
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_lu(sz: usize) {
+    let a = Array::random([sz, sz], Uniform::new(-10., 10.));
+    let (p, l, u) = lu_decomposition(a.view()).unwrap();
+
+    assert!(diff_unit(p.view()) < EPS);
+    assert!(diff_triag(u.view()) < EPS);
+    assert!(diff_triag(l.t()) < EPS);
+    assert!(l.iter().all(|x| x.abs() <= 1.));
+    assert!(diff_rel(p.dot(&a).view(), l.dot(&u).view()) < EPS);
+
+    let b = Array::random([sz, 3], Uniform::new(-10., 10.));
+    let x = solve(a.view(), b.view()).unwrap();
+    assert!(diff_rel(b.view(), a.dot(&x).view()) < EPS);
+
+    let inv = inverse(a.view()).unwrap();
+    assert!(diff_rel(Matrix::eye(sz).view(), a.dot(&inv).view()) < EPS);
+}
+
+#[test]
+fn test_lu() {
+    for sz in [1, 2, 3, 5, 10, 20, 50] {
+        for _ in 0..10 {
+            random_check_lu(sz);
+        }
+    }
+}
+
+#[test]
+fn test_solve_vector() {
+    let a = ndarray::array![[0., 2., 1.], [1., 1., 0.], [3., 0., 1.]];
+    let b = ndarray::array![5., 3., 4.];
+    let x = solve_vector(a.view(), b.view()).unwrap();
+
+    assert!((&x - &ndarray::array![1., 2., 1.])
+        .iter()
+        .all(|d| d.abs() < EPS));
+}
+
+#[test]
+fn test_determinant() {
+    let a = ndarray::array![[0., 2., 1.], [1., 1., 0.], [3., 0., 1.]];
+    assert!((determinant(a.view()).unwrap() + 5.).abs() < EPS);
+
+    let a = Array::random([10, 10], Uniform::new(-10., 10.));
+    let b = Array::random([10, 10], Uniform::new(-10., 10.));
+    let (da, db) = (
+        determinant(a.view()).unwrap(),
+        determinant(b.view()).unwrap(),
+    );
+    let dab = determinant(a.dot(&b).view()).unwrap();
+    assert!((dab - da * db).abs() < EPS * dab.abs());
+}
+
+#[test]
+fn test_singular() {
+    let a = ndarray::array![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]];
+    let b = ndarray::array![[1.], [1.], [1.]];
+
+    assert_eq!(solve(a.view(), b.view()), Err(QRError::Singular));
+    assert_eq!(inverse(a.view()), Err(QRError::Singular));
+    assert!(determinant(a.view()).unwrap().abs() < EPS);
+    assert!(lu_decomposition(a.view()).is_ok());
+}
@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_stable(sz: usize, discrete: bool) -> Matrix {
+    let a = Array::random([sz, sz], Uniform::new(-1., 1.));
+    if discrete {
+        a / (2. * sz as f64)
+    } else {
+        a - 2. * sz as f64 * Matrix::eye(sz)
+    }
+}
+
+#[test]
+fn test_lyapunov() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        let a = Array::random([sz, sz], Uniform::new(-10., 10.)) + 5. * Matrix::eye(sz);
+        let q = Array::random([sz, sz], Uniform::new(-10., 10.));
+        let x = lyapunov(a.view(), q.view()).unwrap();
+        let res = a.dot(&x) + x.dot(&a.t());
+        assert!(diff_rel((-&q).view(), res.view()) < EPS);
+
+        let a = Array::random([sz, sz], Uniform::new(-1., 1.));
+        let x = discrete_lyapunov(a.view(), q.view()).unwrap();
+        let res = a.dot(&x).dot(&a.t()) - &x;
+        assert!(diff_rel((-&q).view(), res.view()) < EPS);
+    }
+}
+
+#[test]
+fn test_lyapunov_singular() {
+    let a = array![[1., 0.], [0., -1.]];
+    let q = Matrix::eye(2);
+    assert_eq!(lyapunov(a.view(), q.view()), Err(QRError::Singular));
+
+    let a = array![[2., 0.], [0., 0.5]];
+    assert_eq!(
+        discrete_lyapunov(a.view(), q.view()),
+        Err(QRError::Singular)
+    );
+    assert_eq!(
+        lyapunov(a.view(), Matrix::eye(3).view()),
+        Err(QRError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn test_lyapunov_cholesky() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        for m in [1, 2, sz, 2 * sz] {
+            for discrete in [false, true] {
+                let a = random_stable(sz, discrete);
+                let b = Array::random([sz, m], Uniform::new(-10., 10.));
+                let q = b.dot(&b.t());
+
+                let (x, l) = if discrete {
+                    (
+                        discrete_lyapunov(a.view(), q.view()).unwrap(),
+                        discrete_lyapunov_cholesky(a.view(), q.view()).unwrap(),
+                    )
+                } else {
+                    (
+                        lyapunov(a.view(), q.view()).unwrap(),
+                        lyapunov_cholesky(a.view(), q.view()).unwrap(),
+                    )
+                };
+
+                assert!(diff_triag(l.t()) < EPS);
+                assert!(l.diag().iter().all(|x| *x >= 0.));
+                assert!(diff_rel(x.view(), l.dot(&l.t()).view()) < EPS);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lyapunov_cholesky_blocks() {
+    // Rotation-like dynamics produce 2 by 2 blocks in the Schur form
+    let a = array![[-0.5, 3., 0.], [-3., -0.5, 1.], [0., 0., -1.]];
+    let b = array![[1.], [0.], [1.]];
+    let l = lyapunov_cholesky(a.view(), b.dot(&b.t()).view()).unwrap();
+    let x = l.dot(&l.t());
+    let res = a.dot(&x) + x.dot(&a.t()) + b.dot(&b.t());
+    assert!(frob_norm(res.view()) < EPS);
+
+    let a = array![[0.5, -0.6, 0.], [0.6, 0.5, 0.], [0.3, 0., 0.2]];
+    let l = discrete_lyapunov_cholesky(a.view(), b.dot(&b.t()).view()).unwrap();
+    let x = l.dot(&l.t());
+    let res = a.dot(&x).dot(&a.t()) - &x + b.dot(&b.t());
+    assert!(frob_norm(res.view()) < EPS);
+}
+
+#[test]
+fn test_lyapunov_cholesky_uncontrollable_block() {
+    use crate::implementation::lyapunov::hammarling;
+
+    // The trailing 2 by 2 block is only excited along its eigenvector (1, 1), so its part of the solution is singular,
+    // while the coupling to the leading state makes the off-diagonal part nonzero
+    let b = array![[1.], [1.], [1.]];
+    for discrete in [false, true] {
+        let mut t = array![[-1., 0.5, 0.3], [0., -2., 1.], [0., 1., -2.]];
+        if discrete {
+            t /= 4.;
+        }
+
+        let r = hammarling(t.view(), b.clone(), discrete).unwrap();
+        let x = r.dot(&r.t());
+        let res = if discrete {
+            t.dot(&x).dot(&t.t()) - &x + b.dot(&b.t())
+        } else {
+            t.dot(&x) + x.dot(&t.t()) + b.dot(&b.t())
+        };
+        assert!(frob_norm(res.view()) < EPS);
+    }
+}
+
+#[test]
+fn test_lyapunov_not_stable() {
+    let a = array![[-1., 1.], [0., 0.5]];
+    let q = Matrix::ones((2, 2));
+    assert_eq!(
+        lyapunov_cholesky(a.view(), q.view()),
+        Err(QRError::NotStable)
+    );
+
+    let a = array![[0.5, 1.], [0., 1.5]];
+    assert_eq!(
+        discrete_lyapunov_cholesky(a.view(), q.view()),
+        Err(QRError::NotStable)
+    );
+}
+
+#[test]
+fn test_lyapunov_cholesky_semidefinite() {
+    let a = random_stable(3, false);
+    let q = array![[1., 0., 1.], [0., 0., 0.], [1., 0., 1.]];
+    let l = lyapunov_cholesky(a.view(), q.view()).unwrap();
+    let x = l.dot(&l.t());
+    let res = a.dot(&x) + x.dot(&a.t()) + &q;
+    assert!(frob_norm(res.view()) < EPS);
+
+    let q = array![[1., 2., 0.], [2., 1., 0.], [0., 0., 1.]];
+    assert!(matches!(
+        lyapunov_cholesky(a.view(), q.view()),
+        Err(QRError::NotPositiveDefinite(_))
+    ));
+    let q = array![[0., 1., 0.], [1., 0., 0.], [0., 0., 0.]];
+    assert!(matches!(
+        discrete_lyapunov_cholesky((a / 10.).view(), q.view()),
+        Err(QRError::NotPositiveDefinite(_))
+    ));
+}
@@ -0,0 +1,262 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::tests::common::random_symmetric;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+#[test]
+fn test_expm_known() {
+    let e = expm(Matrix::zeros((3, 3)).view()).unwrap();
+    assert!(diff_rel(Matrix::eye(3).view(), e.view()) < EPS);
+
+    let e = expm(array![[0., 1.], [0., 0.]].view()).unwrap();
+    assert!(diff_rel(array![[1., 1.], [0., 1.]].view(), e.view()) < EPS);
+
+    for t in [0.001, 0.1, 1., 3., 10., 100.] {
+        let e = expm(array![[0., -t], [t, 0.]].view()).unwrap();
+        let r = array![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+        assert!(diff_rel(r.view(), e.view()) < EPS);
+    }
+
+    let e = expm(array![[1., 0.], [0., -2.]].view()).unwrap();
+    assert!(
+        diff_rel(
+            array![[1f64.exp(), 0.], [0., (-2f64).exp()]].view(),
+            e.view()
+        ) < EPS
+    );
+}
+
+#[test]
+fn test_expm_inverse() {
+    for sz in [1, 2, 3, 5, 10] {
+        for scale in [0.01, 0.1, 1.] {
+            let a = Array::random([sz, sz], Uniform::new(-scale, scale));
+            let e = expm(a.view()).unwrap();
+            let f = expm((-&a).view()).unwrap();
+            assert!(diff_rel(Matrix::eye(sz).view(), e.dot(&f).view()) < EPS);
+        }
+    }
+}
+
+#[test]
+fn test_expm_symmetric() {
+    for sz in [1, 2, 3, 5, 10] {
+        let a = random_symmetric(sz) / 10.;
+        let e = expm(a.view()).unwrap();
+        let f = expm_opts(a.view(), &SYMMETRIC_OPTS).unwrap();
+        assert!(diff_rel(e.view(), f.view()) < EPS);
+
+        let opts = QROptions {
+            accumulate_sim_transforms: false,
+            ..SYMMETRIC_OPTS
+        };
+        let f = expm_opts(a.view(), &opts).unwrap();
+        assert!(diff_rel(e.view(), f.view()) < EPS);
+    }
+}
+
+fn exp_derivatives(z: Complex, _k: usize) -> Complex {
+    z.exp()
+}
+
+#[test]
+fn test_funm_known() {
+    let j = array![[1., 1., 0.], [0., 1., 1.], [0., 0., 1.]];
+    let s = sinm(j.view()).unwrap();
+    let (s1, c1) = (1f64.sin(), 1f64.cos());
+    let r = array![[s1, c1, -s1 / 2.], [0., s1, c1], [0., 0., s1]];
+    assert!(diff_rel(r.view(), s.view()) < EPS);
+
+    for t in [0.1, 1., 3., 10.] {
+        let e = funm(array![[0., -t], [t, 0.]].view(), exp_derivatives).unwrap();
+        let r = array![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+        assert!(diff_rel(r.view(), e.view()) < EPS);
+    }
+
+    let c = coshm(array![[2., 0.], [0., -1.]].view()).unwrap();
+    let r = array![[2f64.cosh(), 0.], [0., 1f64.cosh()]];
+    assert!(diff_rel(r.view(), c.view()) < EPS);
+}
+
+#[test]
+fn test_funm_exp() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        let a = Array::random([sz, sz], Uniform::new(-1., 1.));
+        let e = expm(a.view()).unwrap();
+        let f = funm(a.view(), exp_derivatives).unwrap();
+        assert!(diff_rel(e.view(), f.view()) < EPS);
+    }
+}
+
+#[test]
+fn test_funm_identities() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        let a = Array::random([sz, sz], Uniform::new(-1., 1.));
+        let (s, c) = (sinm(a.view()).unwrap(), cosm(a.view()).unwrap());
+        let id = s.dot(&s) + c.dot(&c);
+        assert!(diff_rel(Matrix::eye(sz).view(), id.view()) < EPS);
+
+        let (sh, ch) = (sinhm(a.view()).unwrap(), coshm(a.view()).unwrap());
+        let e = expm(a.view()).unwrap();
+        assert!(diff_rel(e.view(), (&sh + &ch).view()) < EPS);
+    }
+}
+
+#[test]
+fn test_funm_symmetric() {
+    for sz in [1, 2, 3, 5, 10] {
+        let a = random_symmetric(sz) / 10.;
+        let s = sinm(a.view()).unwrap();
+        let f = funm_opts(
+            a.view(),
+            |z, k| match k % 4 {
+                0 => z.sin(),
+                1 => z.cos(),
+                2 => -z.sin(),
+                _ => -z.cos(),
+            },
+            &SYMMETRIC_OPTS,
+        )
+        .unwrap();
+        assert!(diff_rel(s.view(), f.view()) < EPS);
+    }
+}
+
+#[test]
+fn test_funm_interleaved_clusters() {
+    // clusters {0, 0.05}, {-100, -99.95} and {100} alternate along the diagonal
+    let d = [0., -100., 100., 0.05, -99.95];
+    let mut a = Matrix::from_diag(&Vector::from(d.to_vec()));
+    for i in 0..4 {
+        a[[i, i + 1]] = 1.;
+    }
+
+    let (s, c) = (sinm(a.view()).unwrap(), cosm(a.view()).unwrap());
+    for i in 0..5 {
+        assert!((s[[i, i]] - d[i].sin()).abs() < EPS);
+    }
+    let id = s.dot(&s) + c.dot(&c);
+    assert!(diff_rel(Matrix::eye(5).view(), id.view()) < EPS);
+    assert!(diff_rel(a.dot(&s).view(), s.dot(&a).view()) < EPS);
+}
+
+#[test]
+fn test_funm_vanishing_derivatives() {
+    // One cluster centred at 0, where f(z) = z^3 has two vanishing derivatives in a row
+    let a = array![[-0.05, 1., 0.], [0., 0., 1.], [0., 0., 0.05]];
+    let f = funm(a.view(), |z, k| match k {
+        0 => z * z * z,
+        1 => 3. * z * z,
+        2 => 6. * z,
+        3 => Complex::from(6.),
+        _ => Complex::from(0.),
+    })
+    .unwrap();
+    assert!(diff_rel(a.dot(&a).dot(&a).view(), f.view()) < EPS);
+}
+
+fn random_positive(sz: usize) -> Matrix {
+    Array::random([sz, sz], Uniform::new(-1., 1.)) + 5. * Matrix::eye(sz)
+}
+
+#[test]
+fn test_sqrtm() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        let a = random_positive(sz);
+        let r = sqrtm(a.view()).unwrap();
+        assert!(diff_rel(a.view(), r.dot(&r).view()) < EPS);
+    }
+
+    let a = array![[0., -4.], [4., 0.]];
+    let r = sqrtm(a.view()).unwrap();
+    let s = 2f64.sqrt();
+    assert!(diff_rel(array![[s, -s], [s, s]].view(), r.view()) < EPS);
+
+    for sz in [1, 2, 3, 5, 10] {
+        let a = random_symmetric(sz);
+        let a = a.dot(&a.t()) + Matrix::eye(sz);
+        let r = sqrtm_opts(a.view(), &SYMMETRIC_OPTS).unwrap();
+        assert!(diff_rel(a.view(), r.dot(&r).view()) < EPS);
+    }
+
+    // the branch check is relative to the size of the matrix
+    let a = 1e-10 * random_positive(5);
+    let r = sqrtm(a.view()).unwrap();
+    assert!(diff_rel(a.view(), r.dot(&r).view()) < EPS);
+    let r = sqrtm((1e-10 * Matrix::eye(3)).view()).unwrap();
+    assert!(diff_rel((1e-5 * Matrix::eye(3)).view(), r.view()) < EPS);
+}
+
+#[test]
+fn test_logm() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        let a = random_positive(sz);
+        let l = logm(a.view()).unwrap();
+        let e = expm(l.view()).unwrap();
+        assert!(diff_rel(a.view(), e.view()) < EPS);
+    }
+
+    for sz in [1, 2, 3, 5, 10] {
+        let a = Array::random([sz, sz], Uniform::new(-0.5, 0.5));
+        let l = logm(expm(a.view()).unwrap().view()).unwrap();
+        assert!(diff_rel(a.view(), l.view()) < EPS);
+    }
+
+    let t = 3f64;
+    let l = logm(array![[t.cos(), -t.sin()], [t.sin(), t.cos()]].view()).unwrap();
+    assert!(diff_rel(array![[0., -t], [t, 0.]].view(), l.view()) < EPS);
+
+    let l = logm((1e-10 * Matrix::eye(3)).view()).unwrap();
+    assert!(diff_rel((1e-10f64.ln() * Matrix::eye(3)).view(), l.view()) < EPS);
+}
+
+#[test]
+fn test_no_principal_branch() {
+    let a = array![[-1., 0.], [0., 2.]];
+    assert_eq!(sqrtm(a.view()), Err(QRError::NoPrincipalBranch));
+    assert_eq!(logm(a.view()), Err(QRError::NoPrincipalBranch));
+
+    let a = array![[1., 1.], [0., 0.]];
+    assert_eq!(sqrtm(a.view()), Err(QRError::NoPrincipalBranch));
+    assert_eq!(logm(a.view()), Err(QRError::NoPrincipalBranch));
+}
+
+#[test]
+fn test_signm() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        let v = random_positive(sz);
+        let d: Vector = (0..sz)
+            .map(|i| {
+                if i % 2 == 0 {
+                    1. + i as f64
+                } else {
+                    -1. - i as f64
+                }
+            })
+            .collect();
+        let s: Vector = d.mapv(f64::signum);
+        let vinv = inverse(v.view()).unwrap();
+        let a = (&v * &d).dot(&vinv);
+
+        let x = signm(a.view()).unwrap();
+        assert!(diff_rel((&v * &s).dot(&vinv).view(), x.view()) < EPS);
+        assert!(diff_rel(Matrix::eye(sz).view(), x.dot(&x).view()) < EPS);
+        assert!(diff_rel(a.dot(&x).view(), x.dot(&a).view()) < EPS);
+    }
+
+    assert_eq!(
+        signm(array![[1., 0.], [0., 0.]].view()),
+        Err(QRError::Singular)
+    );
+    assert_eq!(
+        signm(array![[0., -1.], [1., 0.]].view()),
+        Err(QRError::ConvergenceFailed)
+    );
+}
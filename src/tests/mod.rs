@@ -1,3 +1,26 @@
 #[cfg(test)]
+mod bisection;
+mod cholesky;
+pub mod common;
+mod invariant_subspace;
+mod jacobi;
+mod lanczos;
+mod least_squares;
+mod lu;
+mod lyapunov;
+mod matrix_functions;
+mod operator;
+mod pinv;
+mod polar;
+mod polynomial;
+mod power;
+mod quadrature;
+mod qz;
+mod riccati;
 mod schur;
+mod singular_values;
+mod sylvester;
+mod symmetric_definite;
+#[allow(clippy::module_inception)]
 mod tests;
+mod tridiagonal;
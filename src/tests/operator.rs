@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn check_operator(op: &impl LinearOperator, m: MatrixView) {
+    let (rows, cols) = op.shape();
+    assert_eq!((rows, cols), (m.nrows(), m.ncols()));
+    assert!(diff_rel(operator_matrix(op).view(), m) < EPS);
+
+    let x = Array::random(cols, Uniform::new(-1., 1.));
+    let y = Array::random(rows, Uniform::new(-1., 1.));
+    let ax = op.apply(x.view());
+    let aty = op.apply_transpose(y.view());
+    assert!((&ax - &m.dot(&x)).iter().all(|v| v.abs() < EPS));
+    assert!((&aty - &m.t().dot(&y)).iter().all(|v| v.abs() < EPS));
+    assert!((y.dot(&ax) - x.dot(&aty)).abs() < EPS);
+}
+
+#[test]
+fn test_linear_operator() {
+    for (rows, cols) in [(1, 1), (3, 5), (10, 10), (20, 7)] {
+        let m = Array::random((rows, cols), Uniform::new(-10., 10.));
+        check_operator(&m, m.view());
+        check_operator(&m.view(), m.view());
+        check_operator(&&m, m.view());
+
+        let op = FnOperator::new(rows, cols, |x| m.dot(&x), |x| m.t().dot(&x));
+        check_operator(&op, m.view());
+        assert_eq!(op.is_square(), rows == cols);
+
+        let s = &m.t().dot(&m);
+        let op = SymmetricFnOperator::new(cols, |x| s.dot(&x));
+        check_operator(&op, s.view());
+        assert!(op.is_square());
+    }
+}
+
+#[test]
+fn test_linear_operator_matrix_free() {
+    // cyclic convolution with a kernel never stored as a matrix
+    let kernel = array![2., -1., 0., 0.5];
+    let n = kernel.len();
+    let convolve = |x: VectorView| -> Vector {
+        (0..n)
+            .map(|i| (0..n).map(|j| kernel[(i + n - j) % n] * x[j]).sum())
+            .collect()
+    };
+    let correlate = |x: VectorView| -> Vector {
+        (0..n)
+            .map(|i| (0..n).map(|j| kernel[(j + n - i) % n] * x[j]).sum())
+            .collect()
+    };
+    let op = FnOperator::new(n, n, convolve, correlate);
+
+    let m = array![
+        [2., 0.5, 0., -1.],
+        [-1., 2., 0.5, 0.],
+        [0., -1., 2., 0.5],
+        [0.5, 0., -1., 2.]
+    ];
+    check_operator(&op, m.view());
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn check_penrose_conditions(a: MatrixView, p: MatrixView) {
+    let ap = a.dot(&p);
+    let pa = p.dot(&a);
+
+    assert!(diff_rel(a, ap.dot(&a).view()) < EPS);
+    assert!(diff_rel(p, pa.dot(&p).view()) < EPS);
+    assert!(diff_symm(ap.view()) < EPS);
+    assert!(diff_symm(pa.view()) < EPS);
+}
+
+#[test]
+fn test_pinv() {
+    for (m, n) in [(1, 1), (3, 2), (2, 3), (10, 10), (20, 5), (5, 20)] {
+        for _ in 0..10 {
+            let a = Array::random([m, n], Uniform::new(-10., 10.));
+            let (p, rank) = pinv_opts(a.view(), 1e-7, &DEFAULT_OPTS).unwrap();
+
+            assert_eq!(rank, m.min(n));
+            check_penrose_conditions(a.view(), p.view());
+        }
+    }
+
+    let a = Array::random([10, 10], Uniform::new(-10., 10.));
+    let (p, _) = pinv_opts(a.view(), 1e-7, &DEFAULT_OPTS).unwrap();
+    assert!(diff_rel(inverse(a.view()).unwrap().view(), p.view()) < EPS);
+}
+
+#[test]
+fn test_pinv_rank_deficient() {
+    let b = Array::random([12, 4], Uniform::new(-10., 10.));
+    let c = Array::random([4, 8], Uniform::new(-10., 10.));
+    let a = b.dot(&c);
+    let (p, rank) = pinv_opts(a.view(), 1e-6, &DEFAULT_OPTS).unwrap();
+
+    assert_eq!(rank, 4);
+    check_penrose_conditions(a.view(), p.view());
+}
+
+#[test]
+fn test_pinv_cutoff() {
+    let a = ndarray::array![[1., 0., 0.], [0., 1e-3, 0.], [0., 0., 1e-6]];
+
+    let (p, rank) = pinv_opts(a.view(), 1e-2, &DEFAULT_OPTS).unwrap();
+    assert_eq!(rank, 1);
+    assert!(
+        diff_rel(
+            ndarray::array![[1., 0., 0.], [0., 0., 0.], [0., 0., 0.]].view(),
+            p.view()
+        ) < EPS
+    );
+
+    let (p, rank) = pinv_opts(a.view(), 1e-4, &DEFAULT_OPTS).unwrap();
+    assert_eq!(rank, 2);
+    assert!((p[[1, 1]] - 1e3).abs() < EPS * 1e3);
+}
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn check_polar(a: MatrixView) {
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    let (u, h) = polar_decomposition(a).unwrap();
+
+    assert_eq!(u.shape(), &[m, n]);
+    assert_eq!(h.shape(), &[n, n]);
+    assert!(diff_rel(a, u.dot(&h).view()) < EPS);
+    assert!(diff_symm(h.view()) < EPS);
+
+    let eye = if m >= n { u.t().dot(&u) } else { u.dot(&u.t()) };
+    assert!(diff_rel(Matrix::eye(m.min(n)).view(), eye.view()) < EPS);
+
+    let (z, _) = symmetric_definite_eigen((&h + &h.t()).view(), Matrix::eye(n).view()).unwrap();
+    let scale = z.iter().fold(1., |acc: f64, x| acc.max(x.abs()));
+    assert!(z.iter().all(|x| *x > -EPS * scale));
+}
+
+#[test]
+fn test_polar() {
+    for (m, n) in [(1, 1), (2, 2), (3, 2), (2, 3), (10, 10), (20, 5), (5, 20)] {
+        let a = Array::random([m, n], Uniform::new(-10., 10.));
+        check_polar(a.view());
+    }
+}
+
+#[test]
+fn test_polar_rank_deficient() {
+    for (m, n, r) in [(2, 2, 1), (5, 5, 2), (10, 6, 3), (6, 10, 3)] {
+        let b = Array::random([m, r], Uniform::new(-10., 10.));
+        let c = Array::random([r, n], Uniform::new(-10., 10.));
+        check_polar(b.dot(&c).view());
+    }
+
+    let (u, h) = polar_decomposition(Matrix::zeros((4, 3)).view()).unwrap();
+    assert!(diff_rel(Matrix::eye(3).view(), u.t().dot(&u).view()) < EPS);
+    assert!(frob_norm(h.view()) < EPS);
+}
+
+#[test]
+fn test_polar_known() {
+    let t = 0.7f64;
+    let q = array![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+    let (u, h) = polar_decomposition(q.view()).unwrap();
+    assert!(diff_rel(q.view(), u.view()) < EPS);
+    assert!(diff_rel(Matrix::eye(2).view(), h.view()) < EPS);
+
+    let a = array![[3., 0.], [0., -2.]];
+    let (u, h) = polar_decomposition(a.view()).unwrap();
+    assert!(diff_rel(array![[1., 0.], [0., -1.]].view(), u.view()) < EPS);
+    assert!(diff_rel(array![[3., 0.], [0., 2.]].view(), h.view()) < EPS);
+}
@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn check_roots(mut roots: Vec<Complex>, expected: &[Complex]) {
+    assert_eq!(roots.len(), expected.len());
+    for x in expected.iter() {
+        let i = (0..roots.len())
+            .min_by(|i, j| {
+                (roots[*i] - x)
+                    .norm()
+                    .partial_cmp(&(roots[*j] - x).norm())
+                    .unwrap()
+            })
+            .unwrap();
+        assert!((roots[i] - x).norm() < EPS * (1. + x.norm()));
+        roots.remove(i);
+    }
+}
+
+fn poly_from_roots(roots: &[f64]) -> Vector {
+    let mut c = vec![1.];
+    for r in roots {
+        let mut next = c.clone();
+        next.push(0.);
+        for (i, x) in c.iter().enumerate() {
+            next[i + 1] -= r * x;
+        }
+        c = next;
+    }
+    Vector::from(c)
+}
+
+#[test]
+fn test_poly_roots_known() {
+    let c = array![1., -6., 11., -6.];
+    let expected: Vec<Complex> = [1., 2., 3.].iter().map(|x| Complex::from(*x)).collect();
+    check_roots(poly_roots(c.view()).unwrap(), &expected);
+
+    let c = array![1., 0., 1.];
+    check_roots(
+        poly_roots(c.view()).unwrap(),
+        &[Complex::new(0., 1.), Complex::new(0., -1.)],
+    );
+
+    let c = array![1., 0., 0., 0., -1.];
+    check_roots(
+        poly_roots(c.view()).unwrap(),
+        &[
+            Complex::from(1.),
+            Complex::from(-1.),
+            Complex::new(0., 1.),
+            Complex::new(0., -1.),
+        ],
+    );
+
+    assert!(poly_roots(array![5.].view()).unwrap().is_empty());
+    assert_eq!(poly_roots(array![0., 0.].view()), Err(QRError::Singular));
+}
+
+#[test]
+fn test_poly_roots_zeros() {
+    // 0 x^4 + 2 x^3 - 2 x^2 + 0 x + 0 = 2 x^2 (x - 1)
+    let c = array![0., 2., -2., 0., 0.];
+    let expected: Vec<Complex> = [1., 0., 0.].iter().map(|x| Complex::from(*x)).collect();
+    check_roots(poly_roots(c.view()).unwrap(), &expected);
+
+    let c = array![0., 0., 3., 0.];
+    check_roots(poly_roots(c.view()).unwrap(), &[Complex::from(0.)]);
+}
+
+#[test]
+fn test_poly_roots_random() {
+    for d in [1, 2, 3, 5, 10] {
+        let roots = Array::random(d, Uniform::new(-10., 10.));
+        let c = poly_from_roots(roots.as_slice().unwrap());
+        let expected: Vec<Complex> = roots.iter().map(|x| Complex::from(*x)).collect();
+        check_roots(poly_roots(c.view()).unwrap(), &expected);
+    }
+}
+
+#[test]
+fn test_poly_roots_high_degree() {
+    // Roots of unity: x^n - 1
+    for n in [10, 50, 100] {
+        let mut c = Vector::zeros(n + 1);
+        c[0] = 1.;
+        c[n] = -1.;
+        let roots = poly_roots(c.view()).unwrap();
+        let expected: Vec<Complex> = (0..n)
+            .map(|k| Complex::from_polar(1., 2. * std::f64::consts::PI * k as f64 / n as f64))
+            .collect();
+        check_roots(roots, &expected);
+    }
+}
+
+#[test]
+fn test_poly_roots_polish() {
+    let roots = [1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.];
+    let c = poly_from_roots(&roots);
+    let unpolished = poly_roots_opts(c.view(), false, &EIGENVALUE_OPTS).unwrap();
+    let polished = poly_roots(c.view()).unwrap();
+
+    let err = |rs: &[Complex]| {
+        rs.iter()
+            .map(|z| {
+                roots
+                    .iter()
+                    .map(|r| (z - r).norm())
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0., f64::max)
+    };
+    assert!(err(&polished) <= err(&unpolished));
+    assert!(err(&polished) < EPS);
+}
@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use std::f64::consts::PI;
+
+const EPS: f64 = 1e-4;
+
+fn random_orthogonal(sz: usize) -> Matrix {
+    let m = Array::random((sz, sz), Uniform::new(-1., 1.));
+    let (q, _) = qr_decomposition(m.view()).unwrap();
+    q
+}
+
+fn check_pair(m: MatrixView, lambda: f64, x: VectorView, expected: f64) {
+    assert!((lambda - expected).abs() < EPS * (1. + expected.abs()));
+    assert!((x.dot(&x) - 1.).abs() < EPS);
+    let r = m.dot(&x) - lambda * &x;
+    assert!(r.dot(&r).sqrt() < EPS * (1. + expected.abs()));
+}
+
+fn laplacian(x: VectorView) -> Vector {
+    let n = x.len();
+    (0..n)
+        .map(|i| {
+            let left = if i > 0 { x[i - 1] } else { 0. };
+            let right = if i + 1 < n { x[i + 1] } else { 0. };
+            2. * x[i] - left - right
+        })
+        .collect()
+}
+
+#[test]
+fn test_power_iteration() {
+    for sz in [1, 2, 5, 10, 30] {
+        let q = random_orthogonal(sz);
+        let mut d = Array::random(sz, Uniform::new(-1., 1.));
+        d[0] = 10.;
+        let m = q.dot(&Matrix::from_diag(&d)).dot(&q.t());
+
+        let (lambda, x, info) = power_iteration(m.view()).unwrap();
+        check_pair(m.view(), lambda, x.view(), 10.);
+        assert!(info.residual <= 1e-8 * 10. * (1. + EPS));
+
+        // nonsymmetric matrix with the same spectrum
+        let s = Array::random((sz, sz), Uniform::new(-1., 1.)) + 3. * Matrix::eye(sz);
+        let m = s
+            .dot(&Matrix::from_diag(&d))
+            .dot(&inverse(s.view()).unwrap());
+        let (lambda, x, _) = power_iteration(m.view()).unwrap();
+        check_pair(m.view(), lambda, x.view(), 10.);
+    }
+}
+
+#[test]
+fn test_shifted_inverse_iteration() {
+    let sz = 20;
+    let q = random_orthogonal(sz);
+    let d: Vector = (0..sz).map(|i| i as f64).collect();
+    let m = q.dot(&Matrix::from_diag(&d)).dot(&q.t());
+
+    for (sigma, expected) in [(5.2, 5.), (-3., 0.), (12.9, 13.), (7., 7.)] {
+        let (lambda, x, _) = shifted_inverse_iteration(m.view(), sigma).unwrap();
+        check_pair(m.view(), lambda, x.view(), expected);
+    }
+}
+
+#[test]
+fn test_rayleigh_quotient_iteration() {
+    let sz = 30;
+    let q = random_orthogonal(sz);
+    let d: Vector = (0..sz).map(|i| i as f64).collect();
+    let m = q.dot(&Matrix::from_diag(&d)).dot(&q.t());
+
+    for k in [0, 7, sz - 1] {
+        let x0: Vector = q.column(k).to_owned() + 0.05 * Array::random(sz, Uniform::new(-1., 1.));
+        let (lambda, x, info) = rayleigh_quotient_iteration(m.view(), x0.view()).unwrap();
+        check_pair(m.view(), lambda, x.view(), k as f64);
+        assert!(info.iterations <= 5);
+        assert!(x.dot(&q.column(k)).abs() > 1. - EPS);
+    }
+}
+
+#[test]
+fn test_vector_iteration_operator() {
+    // The second difference matrix has eigenvalues 2 - 2 cos(k pi / (n + 1))
+    let n = 50;
+    let value = |k: usize| 2. - 2. * (k as f64 * PI / (n + 1) as f64).cos();
+    let d = Vector::from_elem(n, 2.);
+    let e = Vector::from_elem(n - 1, -1.);
+    let mut m = Matrix::from_diag(&d);
+    for (k, x) in e.iter().enumerate() {
+        m[[k + 1, k]] = *x;
+        m[[k, k + 1]] = *x;
+    }
+
+    let op = SymmetricFnOperator::new(n, laplacian);
+    let (lambda, x, _) = power_iteration_operator(&op).unwrap();
+    check_pair(m.view(), lambda, x.view(), value(n));
+
+    let (lambda, x, _) =
+        shifted_inverse_iteration_operator(&op, |b| solve_vector(m.view(), b).unwrap()).unwrap();
+    check_pair(m.view(), lambda, x.view(), value(1));
+
+    let x0: Vector = (0..n)
+        .map(|i| ((i + 1) as f64 * 3. * PI / (n + 1) as f64).sin() + 0.01)
+        .collect();
+    let (lambda, x, _) = rayleigh_quotient_iteration_operator(
+        &op,
+        |s, b| solve_vector((&m - &(s * Matrix::eye(n))).view(), b).ok(),
+        x0.view(),
+    )
+    .unwrap();
+    check_pair(m.view(), lambda, x.view(), value(3));
+}
+
+#[test]
+fn test_vector_iteration_failures() {
+    // eigenvalues 1 and -1 have the same absolute value
+    let m = array![[0., 1.], [1., 0.]];
+    let opts = QROptions {
+        iterations: 100,
+        ..DEFAULT_OPTS
+    };
+    let x0 = array![1., 0.3];
+    assert_eq!(
+        power_iteration_opts(m.view(), &opts),
+        Err(QRError::ConvergenceFailed)
+    );
+    assert!(rayleigh_quotient_iteration_opts(m.view(), x0.view(), &opts).is_ok());
+
+    assert_eq!(
+        rayleigh_quotient_iteration(m.view(), array![1., 0., 0.].view()),
+        Err(QRError::DimensionMismatch)
+    );
+    assert_eq!(
+        rayleigh_quotient_iteration(m.view(), Vector::zeros(2).view()),
+        Err(QRError::InvalidParameter)
+    );
+    assert_eq!(
+        power_iteration(array![[1., f64::NAN], [0., 1.]].view()),
+        Err(QRError::NotFinite)
+    );
+    assert_eq!(
+        power_iteration(array![[1., 2., 3.], [4., 5., 6.]].view()),
+        Err(QRError::NotSquare)
+    );
+    assert_eq!(
+        power_iteration_operator(&array![[1., 2., 3.], [4., 5., 6.]]),
+        Err(QRError::NotSquare)
+    );
+    assert_eq!(
+        power_iteration_operator(&SymmetricFnOperator::new(3, |_| Vector::zeros(2))),
+        Err(QRError::DimensionMismatch)
+    );
+}
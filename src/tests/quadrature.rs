@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use crate::*;
+
+use std::f64::consts::PI;
+
+const EPS: f64 = 1e-10;
+
+fn integrate(rule: QuadratureRule, n: usize, f: impl Fn(f64) -> f64) -> f64 {
+    let (x, w) = gauss_quadrature(rule, n).unwrap();
+    x.iter().zip(w.iter()).map(|(x, w)| w * f(*x)).sum()
+}
+
+#[test]
+fn test_gauss_legendre() {
+    for n in [1, 2, 3, 5, 10, 20, 100] {
+        let (x, w) = gauss_quadrature(QuadratureRule::Legendre, n).unwrap();
+        assert!(x.iter().zip(x.iter().skip(1)).all(|(a, b)| a < b));
+        assert!((w.sum() - 2.).abs() < EPS);
+
+        let d = 2 * n - 2;
+        let exact = 2. / (d as f64 + 1.);
+        assert!((integrate(QuadratureRule::Legendre, n, |x| x.powi(d as i32)) - exact).abs() < EPS);
+    }
+
+    let approx = integrate(QuadratureRule::Legendre, 20, f64::cos);
+    assert!((approx - 2. * 1f64.sin()).abs() < EPS);
+}
+
+#[test]
+fn test_gauss_hermite() {
+    for n in [1, 2, 3, 5, 10, 20] {
+        assert!((integrate(QuadratureRule::Hermite, n, |_| 1.) - PI.sqrt()).abs() < EPS);
+    }
+    // Integral of x^4 exp(-x^2) is 3 sqrt(pi) / 4
+    let approx = integrate(QuadratureRule::Hermite, 3, |x| x.powi(4));
+    assert!((approx - 0.75 * PI.sqrt()).abs() < EPS);
+}
+
+#[test]
+fn test_gauss_laguerre() {
+    // Integral of x^k x^alpha exp(-x) is Gamma(k + alpha + 1)
+    for n in [1, 2, 3, 5, 10] {
+        let k = 2 * n - 1;
+        let fact: f64 = (1..=k).map(|i| i as f64).product();
+        let approx = integrate(QuadratureRule::Laguerre(0.), n, |x| x.powi(k as i32));
+        assert!((approx / fact - 1.).abs() < EPS);
+    }
+
+    let approx = integrate(QuadratureRule::Laguerre(0.5), 4, |x| x * x);
+    assert!((approx - 15. / 8. * PI.sqrt()).abs() < EPS);
+}
+
+#[test]
+fn test_gauss_jacobi() {
+    let (x, w) = gauss_quadrature(QuadratureRule::Jacobi(0., 0.), 7).unwrap();
+    let (y, v) = gauss_quadrature(QuadratureRule::Legendre, 7).unwrap();
+    assert!((&x - &y).iter().all(|d| d.abs() < EPS));
+    assert!((&w - &v).iter().all(|d| d.abs() < EPS));
+
+    // Chebyshev nodes cos((2i - 1) pi / 2n) with equal weights pi / n
+    for n in [1, 2, 5, 20] {
+        let (x, w) = gauss_quadrature(QuadratureRule::Jacobi(-0.5, -0.5), n).unwrap();
+        for i in 0..n {
+            let node = ((2 * (n - i) - 1) as f64 * PI / (2 * n) as f64).cos();
+            assert!((x[i] - node).abs() < EPS);
+            assert!((w[i] - PI / n as f64).abs() < EPS);
+        }
+    }
+
+    // Integral of (1 - x) (1 + x)^2 over [-1, 1] is 4 / 3
+    let approx = integrate(QuadratureRule::Jacobi(1., 2.), 1, |_| 1.);
+    assert!((approx - 4. / 3.).abs() < EPS);
+}
+
+#[test]
+fn test_gauss_invalid() {
+    assert_eq!(
+        gauss_quadrature(QuadratureRule::Laguerre(-1.), 3),
+        Err(QRError::InvalidParameter)
+    );
+    assert_eq!(
+        gauss_quadrature(QuadratureRule::Jacobi(0., -2.), 3),
+        Err(QRError::InvalidParameter)
+    );
+    let (x, w) = gauss_quadrature(QuadratureRule::Hermite, 0).unwrap();
+    assert_eq!((x.len(), w.len()), (0, 0));
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_qz(sz: usize, rank_b: usize) {
+    let a = Array::random([sz, sz], Uniform::new(-10., 10.));
+    let mut b = Array::random([sz, sz], Uniform::new(-10., 10.));
+    for i in rank_b..sz {
+        b.row_mut(i).fill(0.);
+    }
+
+    let (s, t, q, z) = generalized_schur_form(a.view(), b.view()).unwrap();
+
+    assert!(diff_subtriag(s.view()) < EPS);
+    assert!(diff_triag(t.view()) < EPS);
+    assert!(diff_unit(q.view()) < EPS);
+    assert!(diff_unit(z.view()) < EPS);
+    assert!(diff_rel(a.view(), q.dot(&s).dot(&z.t()).view()) < EPS);
+    if rank_b > 0 {
+        assert!(diff_rel(b.view(), q.dot(&t).dot(&z.t()).view()) < EPS);
+    }
+
+    let eigs = generalized_eigenvalues(a.view(), b.view()).unwrap();
+    let infinite = eigs.iter().filter(|(_, beta)| *beta < EPS).count();
+    assert_eq!(eigs.len(), sz);
+    assert_eq!(infinite, sz - rank_b);
+}
+
+#[test]
+fn test_qz() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        for _ in 0..10 {
+            random_check_qz(sz, sz);
+        }
+    }
+}
+
+#[test]
+fn test_qz_singular() {
+    for sz in [2, 3, 5, 10, 20] {
+        for rank_b in [sz - 1, sz / 2] {
+            random_check_qz(sz, rank_b);
+        }
+    }
+}
+
+#[test]
+fn test_qz_standard_eigenvalues() {
+    let a = Array::random([8, 8], Uniform::new(-10., 10.));
+    let mut ev = eigenvalues(a.view()).unwrap();
+    let mut gev: Vec<Complex> = generalized_eigenvalues(a.view(), Matrix::eye(8).view())
+        .unwrap()
+        .iter()
+        .map(|(alpha, beta)| alpha / beta)
+        .collect();
+
+    let key = |x: &Complex, y: &Complex| (x.re, x.im).partial_cmp(&(y.re, y.im)).unwrap();
+    ev.sort_by(key);
+    gev.sort_by(key);
+    for (x, y) in ev.iter().zip(gev.iter()) {
+        assert!((x - y).norm() < EPS * (1. + x.norm()));
+    }
+}
+
+fn check_pairs(eigs: &[(Complex, f64)], expected: &[f64]) {
+    // expected eigenvalues in the descending order, infinite ones as f64::INFINITY
+    let mut values: Vec<f64> = eigs
+        .iter()
+        .map(|(alpha, beta)| {
+            assert!(*beta >= 0. && alpha.im == 0.);
+            if *beta < EPS * alpha.norm() {
+                f64::INFINITY
+            } else {
+                alpha.re / beta
+            }
+        })
+        .collect();
+    values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    assert_eq!(values.len(), expected.len());
+    for (x, y) in values.iter().zip(expected.iter()) {
+        assert!(x == y || (x - y).abs() < EPS * (1. + y.abs()));
+    }
+}
+
+#[test]
+fn test_qz_real_blocks() {
+    use crate::implementation::qz::{extract_generalized_eigenvalues, qz_block_reduction};
+    use ndarray::array;
+
+    // unreduced 2 by 2 blocks with a real pair and with a singular B block
+    let cases = [
+        (array![[2., 1.], [1., 2.]], Matrix::eye(2), [3., 1.]),
+        (
+            array![[1., 2.], [3., 4.]],
+            array![[0., 1.], [0., 2.]],
+            [f64::INFINITY, 2.],
+        ),
+        (
+            array![[1., 2.], [3., 4.]],
+            array![[1., 1.], [0., 0.]],
+            [f64::INFINITY, -2.],
+        ),
+    ];
+
+    for (a, b, expected) in cases.iter() {
+        check_pairs(
+            &extract_generalized_eigenvalues(a.view(), b.view()),
+            expected,
+        );
+        check_pairs(
+            &generalized_eigenvalues(a.view(), b.view()).unwrap(),
+            expected,
+        );
+
+        let (mut s, mut t) = (a.clone(), b.clone());
+        let (mut q, mut z) = (Matrix::eye(2), Matrix::eye(2));
+        qz_block_reduction(
+            s.view_mut(),
+            t.view_mut(),
+            q.view_mut(),
+            z.view_mut(),
+            &DEFAULT_OPTS,
+        );
+        assert_eq!(s[[1, 0]], 0.);
+        assert!(diff_rel(a.view(), q.dot(&s).dot(&z.t()).view()) < EPS);
+        assert!(diff_rel(b.view(), q.dot(&t).dot(&z.t()).view()) < EPS);
+        check_pairs(
+            &extract_generalized_eigenvalues(s.view(), t.view()),
+            expected,
+        );
+    }
+}
+
+#[test]
+fn test_qz_reorder() {
+    use crate::implementation::qz::extract_generalized_eigenvalues;
+    use crate::implementation::reorder::reorder_generalized_schur;
+
+    for sz in [2, 3, 5, 10] {
+        let a = Array::random([sz, sz], Uniform::new(-10., 10.));
+        let b = Array::random([sz, sz], Uniform::new(-10., 10.));
+        let (mut s, mut t, mut q, mut z) = generalized_schur_form(a.view(), b.view()).unwrap();
+
+        // move the eigenvalues of smallest modulus to the front, cutting between two distinct moduli
+        let modulus = |(alpha, beta): &(Complex, f64)| alpha.norm() / beta.abs();
+        let moduli: Vec<f64> = extract_generalized_eigenvalues(s.view(), t.view())
+            .iter()
+            .map(modulus)
+            .collect();
+        let mut sorted = moduli.clone();
+        sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let k = match (1..sz).find(|&k| sorted[k] > sorted[k - 1] * (1. + EPS)) {
+            Some(k) => k,
+            None => continue,
+        };
+        let cut = (sorted[k - 1] + sorted[k]) / 2.;
+        let select: Vec<bool> = moduli.iter().map(|x| *x < cut).collect();
+
+        let m = reorder_generalized_schur(
+            s.view_mut(),
+            t.view_mut(),
+            q.view_mut(),
+            z.view_mut(),
+            &select,
+        )
+        .unwrap();
+        assert_eq!(m, select.iter().filter(|x| **x).count());
+
+        assert!(diff_subtriag(s.view()) < EPS);
+        assert!(diff_triag(t.view()) < EPS);
+        assert!(diff_unit(q.view()) < EPS);
+        assert!(diff_unit(z.view()) < EPS);
+        assert!(diff_rel(a.view(), q.dot(&s).dot(&z.t()).view()) < EPS);
+        assert!(diff_rel(b.view(), q.dot(&t).dot(&z.t()).view()) < EPS);
+
+        let leading = extract_generalized_eigenvalues(s.view(), t.view());
+        for (i, eig) in leading.iter().enumerate() {
+            assert_eq!(i < m, modulus(eig) < cut);
+        }
+    }
+}
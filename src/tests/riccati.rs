@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::implementation::common::extract_eigenvalues;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_system(n: usize, m: usize) -> (Matrix, Matrix, Matrix, Matrix) {
+    let a = Array::random([n, n], Uniform::new(-1., 1.));
+    let b = Array::random([n, m], Uniform::new(-1., 1.));
+    let c = Array::random([n, n], Uniform::new(-1., 1.));
+    let q = c.t().dot(&c) + Matrix::eye(n);
+    let r = Matrix::eye(m);
+    (a, b, q, r)
+}
+
+fn check_closed_loop(k: MatrixView, l: &[Complex], cl: MatrixView, discrete: bool) {
+    let mut eigs = extract_eigenvalues(schur_form_opts(cl, &EIGENVALUE_OPTS).unwrap().0.view());
+    assert_eq!(eigs.len(), l.len());
+    for x in l.iter() {
+        assert!(if discrete { x.norm() < 1. } else { x.re < 0. });
+        let i = (0..eigs.len())
+            .min_by(|i, j| {
+                (eigs[*i] - x)
+                    .norm()
+                    .partial_cmp(&(eigs[*j] - x).norm())
+                    .unwrap()
+            })
+            .unwrap();
+        assert!((eigs[i] - x).norm() < EPS * (1. + frob_norm(k)));
+        eigs.remove(i);
+    }
+}
+
+#[test]
+fn test_care() {
+    for (n, m) in [(1, 1), (2, 1), (3, 2), (5, 2), (10, 3), (20, 5)] {
+        let (a, b, q, r) = random_system(n, m);
+        let (x, l) = care(a.view(), b.view(), q.view(), r.view()).unwrap();
+
+        let res = a.t().dot(&x) + x.dot(&a) - x.dot(&b).dot(&b.t()).dot(&x) + &q;
+        assert!(frob_norm(res.view()) < EPS * frob_norm(x.view()).max(1.));
+        assert!(diff_symm(x.view()) < EPS);
+
+        let k = b.t().dot(&x);
+        check_closed_loop(k.view(), &l, (&a - &b.dot(&k)).view(), false);
+    }
+}
+
+#[test]
+fn test_dare() {
+    for (n, m) in [(1, 1), (2, 1), (3, 2), (5, 2), (10, 3), (20, 5)] {
+        let (a, b, q, r) = random_system(n, m);
+        let (x, l) = dare(a.view(), b.view(), q.view(), r.view()).unwrap();
+
+        let btxa = b.t().dot(&x).dot(&a);
+        let s = &r + &b.t().dot(&x).dot(&b);
+        let k = solve(s.view(), btxa.view()).unwrap();
+        let res = a.t().dot(&x).dot(&a) - &x - btxa.t().dot(&k) + &q;
+        assert!(frob_norm(res.view()) < EPS * frob_norm(x.view()).max(1.));
+        assert!(diff_symm(x.view()) < EPS);
+
+        check_closed_loop(k.view(), &l, (&a - &b.dot(&k)).view(), true);
+    }
+}
+
+#[test]
+fn test_dare_singular() {
+    for (n, m) in [(2, 1), (3, 2), (5, 2), (10, 3)] {
+        let (a, b, q, r) = random_system(n, m);
+        // A of rank n - 1, so it has a zero eigenvalue
+        let c = Array::random([n, n - 1], Uniform::new(-1., 1.));
+        let a = c.dot(&a.slice(ndarray::s![0..n - 1, ..]));
+        let (x, l) = dare(a.view(), b.view(), q.view(), r.view()).unwrap();
+
+        let btxa = b.t().dot(&x).dot(&a);
+        let s = &r + &b.t().dot(&x).dot(&b);
+        let k = solve(s.view(), btxa.view()).unwrap();
+        let res = a.t().dot(&x).dot(&a) - &x - btxa.t().dot(&k) + &q;
+        assert!(frob_norm(res.view()) < EPS * frob_norm(x.view()).max(1.));
+
+        check_closed_loop(k.view(), &l, (&a - &b.dot(&k)).view(), true);
+    }
+}
+
+#[test]
+fn test_riccati_scalar() {
+    let one = array![[1.]];
+    let (x, l) = care(one.view(), one.view(), one.view(), one.view()).unwrap();
+    assert!((x[[0, 0]] - (1. + 2f64.sqrt())).abs() < EPS);
+    assert!((l[0].re + 2f64.sqrt()).abs() < EPS);
+
+    let (x, l) = dare(one.view(), one.view(), one.view(), one.view()).unwrap();
+    let golden = (1. + 5f64.sqrt()) / 2.;
+    assert!((x[[0, 0]] - golden).abs() < EPS);
+    assert!((l[0].re - 1. / (1. + golden)).abs() < EPS);
+
+    // Singular A: X = Q and the optimal gain is zero
+    let zero = array![[0.]];
+    let (x, l) = dare(zero.view(), one.view(), one.view(), one.view()).unwrap();
+    assert!((x[[0, 0]] - 1.).abs() < EPS);
+    assert!(l[0].norm() < EPS);
+}
+
+#[test]
+fn test_riccati_no_stabilizing_solution() {
+    let (one, zero) = (array![[1.]], array![[0.]]);
+    assert_eq!(
+        care(one.view(), zero.view(), one.view(), one.view()),
+        Err(QRError::NoStabilizingSolution)
+    );
+    assert_eq!(
+        care(zero.view(), zero.view(), zero.view(), one.view()),
+        Err(QRError::NoStabilizingSolution)
+    );
+
+    let two = array![[2.]];
+    assert_eq!(
+        dare(two.view(), zero.view(), one.view(), one.view()),
+        Err(QRError::NoStabilizingSolution)
+    );
+    assert_eq!(
+        care(one.view(), one.view(), one.view(), (-&one).view()),
+        Err(QRError::NotPositiveDefinite(0))
+    );
+}
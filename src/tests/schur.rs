@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use crate::implementation::checks::*;
+use crate::tests::common::random_symmetric;
 use crate::*;
 
 use ndarray::Array;
@@ -63,6 +64,46 @@ fn test_qr_francis() {
     }
 }
 
+#[test]
+fn test_qr_francis_cyclic() {
+    // Cyclic permutations are orthogonal, so the standard shifts make no progress on them
+    for sz in [3, 4, 5, 10, 20] {
+        let mut a = Matrix::zeros((sz, sz));
+        for i in 0..sz {
+            a[[(i + 1) % sz, i]] = 1.;
+        }
+        let opts = QROptions {
+            algorithm: QRAlgorithm::Francis,
+            ..OPTS
+        };
+        let (t, u) = schur_form_opts(a.view(), &opts).unwrap();
+
+        assert!(diff_subtriag(t.view()) < EPS);
+        assert!(diff_unit(u.view()) < EPS);
+        assert!(diff_rel(a.view(), u.dot(&t).dot(&u.t()).view()) < EPS);
+    }
+}
+
+#[test]
+fn test_qr_francis_reducible() {
+    // A zero first column stays zero in the Hessenberg form and splits off a 1 by 1 block at the top
+    for sz in [3, 5, 10, 20] {
+        for _ in 0..10 {
+            let mut a = Array::random([sz, sz], Uniform::new(-10., 10.));
+            a.column_mut(0).fill(0.);
+            let opts = QROptions {
+                algorithm: QRAlgorithm::Francis,
+                ..OPTS
+            };
+            let (t, u) = schur_form_opts(a.view(), &opts).unwrap();
+
+            assert!(diff_subtriag(t.view()) < EPS);
+            assert!(diff_unit(u.view()) < EPS);
+            assert!(diff_rel(a.view(), u.dot(&t).dot(&u.t()).view()) < EPS);
+        }
+    }
+}
+
 fn random_check_qr_symmetric(sz: usize) {
     let mut a = Array::random([sz, sz], Uniform::new(-10., 10.));
     for i in 0..sz {
@@ -90,3 +131,73 @@ fn test_qr_symmetric() {
         }
     }
 }
+
+fn random_check_tridiagonal_solver(sz: usize, clustered: bool, algorithm: QRAlgorithm) {
+    let mut a = random_symmetric(sz);
+
+    if clustered {
+        // few distinct eigenvalues, most of the secular equation deflates
+        let (q, _) = qr_decomposition(a.view()).unwrap();
+        let d: Vector = (0..sz).map(|i| (i % 3) as f64).collect();
+        a = q.dot(&Matrix::from_diag(&d)).dot(&q.t());
+    }
+
+    let opts = QROptions { algorithm, ..OPTS };
+    let (t, u) = schur_form_opts(a.view(), &opts).unwrap();
+
+    assert!(diff_triag(t.view()) < EPS);
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(diff_rel(a.view(), u.dot(&t).dot(&u.t()).view()) < EPS);
+}
+
+#[test]
+fn test_divide_and_conquer() {
+    for sz in [1, 2, 3, 5, 10, 20, 50, 100] {
+        for _ in 0..5 {
+            random_check_tridiagonal_solver(sz, false, QRAlgorithm::DivideAndConquer);
+            random_check_tridiagonal_solver(sz, true, QRAlgorithm::DivideAndConquer);
+        }
+    }
+}
+
+#[test]
+fn test_mrrr() {
+    for sz in [1, 2, 3, 5, 10, 20, 50, 100] {
+        for _ in 0..5 {
+            random_check_tridiagonal_solver(sz, false, QRAlgorithm::Mrrr);
+            random_check_tridiagonal_solver(sz, true, QRAlgorithm::Mrrr);
+        }
+    }
+}
+
+#[test]
+fn test_divide_and_conquer_failure() {
+    // leaves that run out of QR steps are reported even without the safety checks
+    let a = random_symmetric(50);
+    let opts = QROptions {
+        algorithm: QRAlgorithm::DivideAndConquer,
+        iterations: 0,
+        do_safety_checks: false,
+        ..OPTS
+    };
+    assert_eq!(
+        schur_form_opts(a.view(), &opts),
+        Err(QRError::ConvergenceFailed)
+    );
+}
+
+#[test]
+fn test_jacobi_failure() {
+    // a single sweep does not diagonalize a random matrix, which is reported even without the safety checks
+    let a = random_symmetric(30);
+    let opts = QROptions {
+        algorithm: QRAlgorithm::Jacobi,
+        iterations: 1,
+        do_safety_checks: false,
+        ..OPTS
+    };
+    assert_eq!(
+        schur_form_opts(a.view(), &opts),
+        Err(QRError::ConvergenceFailed)
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::Array;
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_singular_values(rows: usize, cols: usize) {
+    let a = Array::random([rows, cols], Uniform::new(-10., 10.));
+    let s = singular_values(a.view()).unwrap();
+    let (_, expected, _) = svd(a.view()).unwrap();
+
+    let k = rows.min(cols);
+    assert_eq!(s.shape()[0], k);
+    assert!(s.iter().zip(s.iter().skip(1)).all(|(a, b)| a >= b));
+    for i in 0..k {
+        assert!((s[i] - expected[i]).abs() < EPS * (1. + expected[0]));
+    }
+    assert!((s.dot(&s) - frob_norm(a.view()).powi(2)).abs() < EPS * (1. + s.dot(&s)));
+}
+
+#[test]
+fn test_singular_values() {
+    for (rows, cols) in [
+        (1, 1),
+        (1, 5),
+        (5, 1),
+        (2, 3),
+        (10, 10),
+        (20, 7),
+        (7, 20),
+        (50, 50),
+    ] {
+        for _ in 0..5 {
+            random_check_singular_values(rows, cols);
+        }
+    }
+}
+
+#[test]
+fn test_singular_values_graded() {
+    // upper bidiagonal with 1 on the diagonal and 2 above: det = 1, and the smallest singular value is about 2^-n
+    let n = 40;
+    let mut b = Matrix::eye(n);
+    let mut bi = Matrix::zeros((n, n));
+    for i in 0..n {
+        if i + 1 < n {
+            b[[i, i + 1]] = 2.;
+        }
+        for j in i..n {
+            bi[[i, j]] = (-2f64).powi((j - i) as i32);
+        }
+    }
+
+    let s = singular_values(b.view()).unwrap();
+    let si = singular_values(bi.view()).unwrap();
+    assert!(s[n - 1] < 1e-10);
+    assert!((s[n - 1] * si[0] - 1.).abs() < 1e-10);
+    assert!(s.iter().map(|x| x.ln()).sum::<f64>().abs() < 1e-10);
+}
+
+#[test]
+fn test_singular_values_degenerate() {
+    let s = singular_values(Matrix::zeros((3, 4)).view()).unwrap();
+    assert_eq!(s, Vector::zeros(3));
+
+    // rank one
+    let u = Array::random([6, 1], Uniform::new(-1., 1.));
+    let v = Array::random([1, 4], Uniform::new(-1., 1.));
+    let s = singular_values(u.dot(&v).view()).unwrap();
+    assert!((s[0] - frob_norm(u.view()) * frob_norm(v.view())).abs() < EPS);
+    assert!(s.iter().skip(1).all(|x| x.abs() < EPS));
+
+    let a = Matrix::from_elem((2, 2), f64::NAN);
+    assert_eq!(singular_values(a.view()), Err(QRError::NotFinite));
+}
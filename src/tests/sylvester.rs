@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_sylvester(m: usize, n: usize) {
+    let a = Array::random([m, m], Uniform::new(-10., 10.)) + 30. * Matrix::eye(m);
+    let b = Array::random([n, n], Uniform::new(-10., 10.)) + 30. * Matrix::eye(n);
+    let c = Array::random([m, n], Uniform::new(-10., 10.));
+
+    let (x, sep) = sylvester(a.view(), b.view(), c.view()).unwrap();
+    assert!(diff_rel(c.view(), (a.dot(&x) + x.dot(&b)).view()) < EPS);
+    assert!(sep > 0.);
+}
+
+#[test]
+fn test_sylvester() {
+    for (m, n) in [(1, 1), (2, 2), (3, 2), (2, 3), (5, 5), (10, 3), (20, 20)] {
+        for _ in 0..5 {
+            random_check_sylvester(m, n);
+        }
+    }
+}
+
+#[test]
+fn test_sylvester_blocks() {
+    // Complex eigenvalue pairs in both A and B exercise the 2 by 2 and 4 by 4 block systems
+    let a = array![[1., -3., 0.5], [3., 1., 0.], [0., 0., 2.]];
+    let b = array![[2., 4.], [-4., 2.]];
+    let c = array![[1., 2.], [3., 4.], [5., 6.]];
+
+    let (x, _) = sylvester(a.view(), b.view(), c.view()).unwrap();
+    assert!(diff_rel(c.view(), (a.dot(&x) + x.dot(&b)).view()) < EPS);
+}
+
+#[test]
+fn test_sylvester_sep() {
+    let a = array![[1., 0.], [0., 2.]];
+    let b = array![[-2. + 1e-3, 0.], [0., 5.]];
+    let c = Matrix::ones((2, 2));
+
+    let (x, sep) = sylvester(a.view(), b.view(), c.view()).unwrap();
+    assert!(diff_rel(c.view(), (a.dot(&x) + x.dot(&b)).view()) < EPS);
+    assert!((sep - 1e-3).abs() < 1e-5);
+
+    let b = array![[-2., 0.], [0., 5.]];
+    assert_eq!(
+        sylvester(a.view(), b.view(), c.view()),
+        Err(QRError::Singular)
+    );
+    assert_eq!(
+        sylvester(a.view(), b.view(), Matrix::ones((3, 2)).view()),
+        Err(QRError::DimensionMismatch)
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::tests::common::*;
+use crate::*;
+
+const EPS: f64 = 1e-4;
+
+fn random_check_symmetric_definite(sz: usize) {
+    let k = random_symmetric(sz);
+    let m = random_spd(sz);
+    let (l, x) = symmetric_definite_eigen(k.view(), m.view()).unwrap();
+
+    assert!(diff_rel(Matrix::eye(sz).view(), x.t().dot(&m).dot(&x).view()) < EPS);
+    assert!(diff_rel(Matrix::from_diag(&l).view(), x.t().dot(&k).dot(&x).view()) < EPS);
+    assert!(
+        diff_rel(
+            k.dot(&x).view(),
+            m.dot(&x).dot(&Matrix::from_diag(&l)).view()
+        ) < EPS
+    );
+    for i in 1..sz {
+        assert!(l[i - 1] >= l[i]);
+    }
+}
+
+#[test]
+fn test_symmetric_definite() {
+    for sz in [1, 2, 3, 5, 10, 20] {
+        for _ in 0..10 {
+            random_check_symmetric_definite(sz);
+        }
+    }
+}
+
+#[test]
+fn test_symmetric_definite_indefinite() {
+    let k = random_symmetric(3);
+    let m = ndarray::array![[2., 0., 0.], [0., -1., 0.], [0., 0., 1.]];
+
+    assert_eq!(
+        symmetric_definite_eigen(k.view(), m.view()),
+        Err(QRError::NotPositiveDefinite(1))
+    );
+}
@@ -67,3 +67,30 @@ fn test_svd() {
         }
     }
 }
+
+#[test]
+fn test_extract_eigenvalues() {
+    use crate::implementation::common::extract_eigenvalues;
+    use ndarray::array;
+
+    // a complex pair, a real pair in an unreduced 2 by 2 block and a trailing 1 by 1 block
+    let t = array![
+        [1., -2., 0., 0., 0.],
+        [2., 1., 0., 0., 0.],
+        [0., 0., 3., 1., 0.],
+        [0., 0., 1., 3., 0.],
+        [0., 0., 0., 0., 7.]
+    ];
+    let eigs = extract_eigenvalues(t.view());
+    let expected = [
+        Complex::new(1., 2.),
+        Complex::new(1., -2.),
+        Complex::from(4.),
+        Complex::from(2.),
+        Complex::from(7.),
+    ];
+    assert_eq!(eigs.len(), expected.len());
+    for (a, b) in eigs.iter().zip(expected.iter()) {
+        assert!((a - b).norm() < EPS);
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use crate::implementation::checks::*;
+use crate::implementation::mrrr::representation_tree;
+use crate::*;
+
+use ndarray::{array, Array};
+use ndarray_rand::rand_distr::Uniform;
+use ndarray_rand::RandomExt;
+use std::f64::consts::PI;
+
+const EPS: f64 = 1e-4;
+
+fn tridiagonal_matrix(d: VectorView, e: VectorView) -> Matrix {
+    let mut m = Matrix::from_diag(&d);
+    for (k, x) in e.iter().enumerate() {
+        m[[k + 1, k]] = *x;
+        m[[k, k + 1]] = *x;
+    }
+    m
+}
+
+fn random_check_tridiagonal(sz: usize) {
+    let d = Array::random(sz, Uniform::new(-10., 10.));
+    let e = Array::random(sz - 1, Uniform::new(-10., 10.));
+    let m = tridiagonal_matrix(d.view(), e.view());
+
+    let (z, u) = tridiagonal_eigen(d.view(), e.view()).unwrap();
+    assert!(diff_unit(u.view()) < EPS);
+    assert!(diff_rel(m.view(), u.dot(&Matrix::from_diag(&z)).dot(&u.t()).view()) < EPS);
+    assert!(z.iter().zip(z.iter().skip(1)).all(|(a, b)| a >= b));
+
+    let values = tridiagonal_eigenvalues(d.view(), e.view()).unwrap();
+    assert!((&values - &z)
+        .iter()
+        .all(|x| x.abs() < EPS * (1. + z[0].abs())));
+
+    let (t, _) = schur_form_opts(m.view(), &SYMMETRIC_OPTS).unwrap();
+    let mut dense = t.diag().to_vec();
+    dense.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    assert!(values
+        .iter()
+        .zip(dense.iter())
+        .all(|(a, b)| (a - b).abs() < EPS * (1. + b.abs())));
+}
+
+#[test]
+fn test_tridiagonal() {
+    for sz in [1, 2, 3, 5, 10, 20, 50] {
+        for _ in 0..5 {
+            random_check_tridiagonal(sz);
+        }
+    }
+}
+
+#[test]
+fn test_tridiagonal_known() {
+    // The second difference matrix has eigenvalues 2 - 2 cos(k pi / (n + 1))
+    let n = 100;
+    let d = Vector::from_elem(n, 2.);
+    let e = Vector::from_elem(n - 1, -1.);
+    let z = tridiagonal_eigenvalues(d.view(), e.view()).unwrap();
+    for (i, x) in z.iter().enumerate() {
+        let k = (n - i) as f64;
+        assert!((x - (2. - 2. * (k * PI / (n + 1) as f64).cos())).abs() < EPS);
+    }
+
+    let z = tridiagonal_eigenvalues(array![1., 2., 3.].view(), Vector::zeros(2).view()).unwrap();
+    assert_eq!(z, array![3., 2., 1.]);
+
+    assert!(
+        tridiagonal_eigenvalues(Vector::zeros(0).view(), Vector::zeros(0).view())
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        tridiagonal_eigenvalues(array![1., 2.].view(), array![1., 2.].view()),
+        Err(QRError::DimensionMismatch)
+    );
+}
+
+#[test]
+fn test_tridiagonal_split() {
+    // Zero off-diagonal entries in the interior split the matrix into independent blocks
+    for sz in [3, 5, 10, 20] {
+        for _ in 0..5 {
+            let d = Array::random(sz, Uniform::new(-10., 10.));
+            let mut e = Array::random(sz - 1, Uniform::new(-10., 10.));
+            e[(sz - 1) / 2] = 0.;
+            e[0] = 0.;
+            let m = tridiagonal_matrix(d.view(), e.view());
+
+            let (z, u) = tridiagonal_eigen(d.view(), e.view()).unwrap();
+            assert!(diff_unit(u.view()) < EPS);
+            assert!(diff_rel(m.view(), u.dot(&Matrix::from_diag(&z)).dot(&u.t()).view()) < EPS);
+
+            let values = tridiagonal_eigenvalues(d.view(), e.view()).unwrap();
+            assert!((&values - &z)
+                .iter()
+                .all(|x| x.abs() < EPS * (1. + z[0].abs())));
+        }
+    }
+}
+
+fn check_tridiagonal_solver(algorithm: QRAlgorithm) {
+    let opts = QROptions {
+        algorithm,
+        ..SYMMETRIC_OPTS
+    };
+
+    // random, second difference (clustered) and Wilkinson (close pairs) matrices, a split one
+    // and glued Wilkinson blocks (tight clusters of close pairs)
+    let n = 301;
+    let wilkinson: Vector = (0..n).map(|i| (i as f64 - (n / 2) as f64).abs()).collect();
+    let glued: Vector = (0..n).map(|i| (i as f64 % 21. - 10.).abs()).collect();
+    let glue: Vector = (1..n)
+        .map(|i| if i % 21 == 0 { 1e-10 } else { 1. })
+        .collect();
+    let mut split = Array::random(n - 1, Uniform::new(-1., 1.));
+    split[n / 3] = 0.;
+    let cases = [
+        (
+            Array::random(n, Uniform::new(-10., 10.)),
+            Array::random(n - 1, Uniform::new(-10., 10.)),
+        ),
+        (Vector::from_elem(n, 2.), Vector::from_elem(n - 1, -1.)),
+        (wilkinson, Vector::ones(n - 1)),
+        (Array::random(n, Uniform::new(-1., 1.)), split),
+        (glued, glue),
+    ];
+
+    for (d, e) in cases.iter() {
+        let m = tridiagonal_matrix(d.view(), e.view());
+        let (z, u) = tridiagonal_eigen_opts(d.view(), e.view(), &opts).unwrap();
+        assert!(diff_unit(u.view()) < EPS);
+        assert!(diff_rel(m.view(), u.dot(&Matrix::from_diag(&z)).dot(&u.t()).view()) < EPS);
+
+        let values = tridiagonal_eigenvalues(d.view(), e.view()).unwrap();
+        assert!((&values - &z)
+            .iter()
+            .all(|x| x.abs() < EPS * (1. + z[0].abs())));
+    }
+}
+
+#[test]
+fn test_tridiagonal_divide_and_conquer() {
+    check_tridiagonal_solver(QRAlgorithm::DivideAndConquer);
+}
+
+#[test]
+fn test_tridiagonal_mrrr() {
+    check_tridiagonal_solver(QRAlgorithm::Mrrr);
+}
+
+#[test]
+fn test_mrrr_glued_wilkinson() {
+    // 20 copies of W21+ glued by 1e-10 have clusters of 20 eigenvalues within about 1e-10,
+    // which the representation tree resolves without the inverse iteration fallback
+    let (m, copies) = (21, 20);
+    let n = m * copies;
+    let d: Vector = (0..n).map(|i| ((i % m) as f64 - 10.).abs()).collect();
+    let e: Vector = (1..n)
+        .map(|i| if i % m == 0 { 1e-10 } else { 1. })
+        .collect();
+
+    let (values, vectors) = representation_tree(d.view(), e.view()).unwrap();
+    assert!(vectors.iter().all(|v| v.is_some()));
+    assert_eq!(values.len(), n);
+
+    let opts = QROptions {
+        algorithm: QRAlgorithm::Mrrr,
+        ..SYMMETRIC_OPTS
+    };
+    let t = tridiagonal_matrix(d.view(), e.view());
+    let (z, u) = tridiagonal_eigen_opts(d.view(), e.view(), &opts).unwrap();
+    assert!(diff_unit(u.view()) < 1e-10);
+    assert!(diff_rel(t.view(), u.dot(&Matrix::from_diag(&z)).dot(&u.t()).view()) < 1e-10);
+}